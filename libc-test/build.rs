@@ -35,6 +35,7 @@ fn do_cc() {
     }
     if target.contains("android") || target.contains("linux") {
         cc::Build::new().file("src/errqueue.c").compile("errqueue");
+        cc::Build::new().file("src/ioctl.c").compile("ioctl");
     }
     if target.contains("linux")
         || target.contains("l4re")
@@ -43,6 +44,9 @@ fn do_cc() {
     {
         cc::Build::new().file("src/sigrt.c").compile("sigrt");
     }
+    if target.contains("cygwin") {
+        cc::Build::new().file("src/siginfo.c").compile("siginfo");
+    }
 }
 
 fn do_ctest() {
@@ -603,38 +607,55 @@ fn test_cygwin(target: &str) {
     cfg.define("_GNU_SOURCE", None);
 
     headers! { cfg:
+        "aio.h",
         "ctype.h",
         "dirent.h",
         "dlfcn.h",
         "errno.h",
         "fcntl.h",
+        "fnmatch.h",
+        "glob.h",
         "grp.h",
         "iconv.h",
+        "ifaddrs.h",
+        "io.h",
         "langinfo.h",
         "limits.h",
         "locale.h",
+        "mntent.h",
+        "mqueue.h",
         "net/if.h",
         "netdb.h",
+        "netinet/in.h",
         "netinet/tcp.h",
         "poll.h",
         "pthread.h",
+        "pty.h",
         "pwd.h",
+        "regex.h",
         "resolv.h",
         "sched.h",
         "semaphore.h",
         "signal.h",
+        "spawn.h",
         "stddef.h",
         "stdlib.h",
         "string.h",
+        "sys/acl.h",
         "sys/cpuset.h",
+        "sys/cygwin.h",
         "sys/ioctl.h",
+        "sys/ipc.h",
         "sys/mman.h",
         "sys/mount.h",
+        "sys/msg.h",
         "sys/param.h",
         "sys/quota.h",
         "sys/random.h",
         "sys/resource.h",
         "sys/select.h",
+        "sys/sem.h",
+        "sys/shm.h",
         "sys/socket.h",
         "sys/statvfs.h",
         "sys/times.h",
@@ -642,12 +663,17 @@ fn test_cygwin(target: &str) {
         "sys/uio.h",
         "sys/un.h",
         "sys/utsname.h",
+        "sys/vfs.h",
+        "sys/xattr.h",
         "syslog.h",
         "termios.h",
         "unistd.h",
         "utime.h",
+        "utmp.h",
+        "utmpx.h",
         "wait.h",
         "wchar.h",
+        "wordexp.h",
     }
 
     cfg.type_name(move |ty, is_struct, is_union| {
@@ -1896,6 +1922,10 @@ fn test_android(target: &str) {
 
             // These are tested in the `linux_elf.rs` file.
             "Elf64_Phdr" | "Elf32_Phdr" => true,
+
+            // This type is not defined by bionic, it only types the ioctl
+            // request helpers.
+            "Ioctl" => true,
             _ => false,
         }
     });
@@ -3353,6 +3383,8 @@ fn test_linux(target: &str) {
     let x86_64_gnux32 = target.contains("gnux32") && x86_64;
     let riscv64 = target.contains("riscv64");
     let uclibc = target.contains("uclibc");
+    // Version of the kernel headers, `(0, 0)` if it can't be determined.
+    let linux_headers = linux_headers_version().unwrap_or((0, 0));
 
     let mut cfg = ctest_cfg();
     cfg.define("_GNU_SOURCE", None);
@@ -3491,9 +3523,13 @@ fn test_linux(target: &str) {
         "linux/if_ether.h",
         "linux/if_tun.h",
         "linux/input.h",
+        // FIXME: requires kernel headers >= 6.1.
+        [!musl]: "linux/io_uring.h",
         "linux/ipv6.h",
         "linux/kexec.h",
         "linux/keyctl.h",
+        // FIXME: requires kernel headers >= 5.13.
+        [!musl]: "linux/landlock.h",
         "linux/magic.h",
         "linux/memfd.h",
         "linux/membarrier.h",
@@ -3525,6 +3561,8 @@ fn test_linux(target: &str) {
         "linux/sockios.h",
         "linux/tls.h",
         "linux/uinput.h",
+        // FIXME: musl CI has old headers
+        [!musl]: "linux/userfaultfd.h",
         "linux/vm_sockets.h",
         "linux/wait.h",
         "sys/fanotify.h",
@@ -3633,7 +3671,15 @@ fn test_linux(target: &str) {
             return true;
         }
         // FIXME: musl CI has old headers
-        if musl && ty.starts_with("uinput_") {
+        if musl && (ty.starts_with("uinput_") || ty.starts_with("uffd")) {
+            return true;
+        }
+        // FIXME: requires >= 6.1 kernel headers
+        if musl
+            && (ty.starts_with("io_uring_")
+                || ty == "io_sqring_offsets"
+                || ty == "io_cqring_offsets")
+        {
             return true;
         }
         // LFS64 types have been removed in musl 1.2.4+
@@ -3701,6 +3747,23 @@ fn test_linux(target: &str) {
 
             // FIXME: requires >= 6.1 kernel headers
             "canxl_frame" => true,
+
+            // FIXME: requires >= 5.13 kernel headers
+            "landlock_path_beneath_attr" if musl => true,
+            // `scoped` needs >= 6.12 kernel headers
+            "landlock_ruleset_attr" if musl || linux_headers < (6, 12) => true,
+            // needs >= 6.7 kernel headers
+            "landlock_net_port_attr" if musl || linux_headers < (6, 7) => true,
+
+            // FIXME: requires >= 5.12 kernel headers
+            "mount_attr" if musl => true,
+            // `mnt_uidmap` and `mnt_gidmap` need >= 6.15 kernel headers
+            "statmount" if musl || linux_headers < (6, 15) => true,
+            // `mnt_ns_id` needs >= 6.11 kernel headers
+            "mnt_id_req" if musl || linux_headers < (6, 11) => true,
+
+            // FIXME: requires >= 6.6 kernel headers
+            "uffdio_poison" => true,
             _ => false,
         }
     });
@@ -3722,8 +3785,14 @@ fn test_linux(target: &str) {
                 || name.starts_with("EPOLL")
                 || name.starts_with("F_")
                 || name.starts_with("FALLOC_FL_")
+                || name.starts_with("FSCONFIG_")
+                || name.starts_with("FSMOUNT_")
+                || name.starts_with("FSOPEN_")
+                || name.starts_with("FSPICK_")
                 || name.starts_with("IFLA_")
                 || name.starts_with("KEXEC_")
+                || name.starts_with("MOUNT_ATTR_")
+                || name.starts_with("MOVE_MOUNT_")
                 || name.starts_with("MS_")
                 || name.starts_with("MSG_")
                 || name.starts_with("OPEN_TREE_")
@@ -3761,6 +3830,24 @@ fn test_linux(target: &str) {
             if name.starts_with("RLIM64") {
                 return true;
             }
+            // FIXME: Requires >= 6.1 kernel headers
+            if name.starts_with("IORING_")
+                || name.starts_with("IOSQE_")
+                || name == "IO_URING_OP_SUPPORTED"
+            {
+                return true;
+            }
+            // FIXME: Requires >= 5.13 kernel headers
+            if name.starts_with("LANDLOCK_") {
+                return true;
+            }
+            // FIXME: musl CI has old headers
+            if name.starts_with("UFFD")
+                || name.starts_with("_UFFDIO_")
+                || name.starts_with("USERFAULTFD_")
+            {
+                return true;
+            }
         }
         match name {
             // These constants are not available if gnu headers have been included
@@ -3982,6 +4069,63 @@ fn test_linux(target: &str) {
             // FIXME: Requires more recent kernel headers
             "HWTSTAMP_TX_ONESTEP_P2P" if musl => true, // linux v5.6+
 
+            // FIXME: Requires more recent kernel headers
+            | "LANDLOCK_ACCESS_FS_TRUNCATE"         // linux v6.2+
+            | "LANDLOCK_ACCESS_FS_IOCTL_DEV"        // linux v6.10+
+            | "LANDLOCK_ACCESS_NET_BIND_TCP"        // linux v6.7+
+            | "LANDLOCK_ACCESS_NET_CONNECT_TCP"     // linux v6.7+
+            | "LANDLOCK_RULE_NET_PORT"              // linux v6.7+
+            | "LANDLOCK_SCOPE_ABSTRACT_UNIX_SOCKET" // linux v6.12+
+            | "LANDLOCK_SCOPE_SIGNAL"               // linux v6.12+
+                => true,
+
+            // FIXME: Requires more recent kernel headers
+            | "SYS_cachestat"            // linux v6.5+
+            | "SYS_fchmodat2"            // linux v6.6+
+            | "SYS_map_shadow_stack"     // linux v6.6+
+            | "SYS_futex_wake"           // linux v6.7+
+            | "SYS_futex_wait"           // linux v6.7+
+            | "SYS_futex_requeue"        // linux v6.7+
+                => true,
+
+            // FIXME: Requires more recent kernel headers
+            | "MOVE_MOUNT_BENEATH"       // linux v6.5+
+            | "FSCONFIG_CMD_CREATE_EXCL" // linux v6.6+
+            | "MNT_ID_REQ_SIZE_VER0"     // linux v6.8+
+            | "MNT_ID_REQ_SIZE_VER1"     // linux v6.11+
+            | "LSMT_ROOT"                // linux v6.8+
+            | "LISTMOUNT_REVERSE"        // linux v6.14+
+            | "SYS_statmount"            // linux v6.8+
+            | "SYS_listmount"            // linux v6.8+
+                => true,
+
+            // FIXME: Requires more recent kernel headers
+            | "STATMOUNT_SB_BASIC"       // linux v6.8+
+            | "STATMOUNT_MNT_BASIC"      // linux v6.8+
+            | "STATMOUNT_PROPAGATE_FROM" // linux v6.8+
+            | "STATMOUNT_MNT_ROOT"       // linux v6.8+
+            | "STATMOUNT_MNT_POINT"      // linux v6.8+
+            | "STATMOUNT_FS_TYPE"        // linux v6.8+
+            | "STATMOUNT_MNT_OPTS"       // linux v6.10+
+            | "STATMOUNT_MNT_NS_ID"      // linux v6.11+
+            | "STATMOUNT_FS_SUBTYPE"     // linux v6.13+
+            | "STATMOUNT_SB_SOURCE"      // linux v6.13+
+            | "STATMOUNT_OPT_ARRAY"      // linux v6.13+
+            | "STATMOUNT_OPT_SEC_ARRAY"  // linux v6.13+
+            | "STATMOUNT_SUPPORTED_MASK" // linux v6.15+
+            | "STATMOUNT_MNT_UIDMAP"     // linux v6.15+
+            | "STATMOUNT_MNT_GIDMAP"     // linux v6.15+
+                => true,
+
+            // FIXME: Requires more recent kernel headers
+            | "UFFD_FEATURE_WP_UNPOPULATED" // linux v6.4+
+            | "UFFD_FEATURE_POISON"         // linux v6.6+
+            | "UFFD_FEATURE_WP_ASYNC"       // linux v6.7+
+            | "_UFFDIO_POISON"              // linux v6.6+
+            | "UFFDIO_POISON"               // linux v6.6+
+            | "UFFDIO_POISON_MODE_DONTWAKE" // linux v6.6+
+                => true,
+
             _ => false,
         }
     });
@@ -4106,7 +4250,9 @@ fn test_linux(target: &str) {
         // `__exit_status` type is a patch which is absent in musl
         (struct_ == "utmpx" && field == "ut_exit" && musl) ||
         // `can_addr` is an anonymous union
-        (struct_ == "sockaddr_can" && field == "can_addr")
+        (struct_ == "sockaddr_can" && field == "can_addr") ||
+        // `arg` is an anonymous union
+        (struct_ == "uffd_msg" && field == "arg")
     });
 
     cfg.volatile_item(|i| {
@@ -4161,6 +4307,12 @@ fn test_linux(target: &str) {
         (struct_ == "sockaddr_vm" && field == "svm_zero") ||
         // the `ifr_ifru` field is an anonymous union
         (struct_ == "ifreq" && field == "ifr_ifru") ||
+        // these fields are anonymous unions in the kernel header
+        (struct_ == "io_uring_sqe" && field.ends_with("__c_anonymous_union")) ||
+        // flexible array members cannot be sized on the C side
+        (struct_ == "io_uring_cqe" && field == "big_cqe") ||
+        (struct_ == "io_uring_probe" && field == "ops") ||
+        (struct_ == "statmount" && field == "str") ||
         // glibc uses a single array `uregs` instead of individual fields.
         (struct_ == "user_regs" && arm)
     });
@@ -4205,6 +4357,8 @@ fn test_linux(target: &str) {
         // layout consistency, but this would be UB for the these types.
         "inotify_event" => true,
         "cmsghdr" => true,
+        "io_uring_cqe" => true,
+        "io_uring_probe" => true,
 
         // FIXME: the call ABI of max_align_t is incorrect on these platforms:
         "max_align_t" if i686 || mips64 || ppc64 => true,
@@ -4382,6 +4536,30 @@ fn which_freebsd() -> Option<i32> {
     }
 }
 
+/// Returns the `(major, minor)` version of the Linux uAPI headers that the C
+/// compiler for the current target uses, if `linux/version.h` is available.
+fn linux_headers_version() -> Option<(u32, u32)> {
+    let mut probe = PathBuf::from(env::var("OUT_DIR").unwrap());
+    probe.push("linux_version.c");
+    std::fs::write(&probe, "#include <linux/version.h>\nLINUX_VERSION_CODE\n").ok()?;
+
+    let expanded = cc::Build::new()
+        .file(&probe)
+        .cargo_metadata(false)
+        .try_expand()
+        .ok()?;
+    let expanded = String::from_utf8(expanded).ok()?;
+    let code: u32 = expanded
+        .lines()
+        .rev()
+        .find(|line| !line.trim().is_empty())?
+        .trim()
+        .parse()
+        .ok()?;
+
+    Some((code >> 16, (code >> 8) & 0xff))
+}
+
 fn test_haiku(target: &str) {
     assert!(target.contains("haiku"));
 
@@ -0,0 +1,22 @@
+#include <sys/ioctl.h>
+#include <linux/fs.h>
+
+// _IO, _IOR, _IOW and _IOWR are macros whose direction and size encoding
+// depends on the architecture. These functions expand them for a few known
+// request numbers so the reimplementations in this package can be checked.
+
+unsigned int ioctl_fs_ioc_getflags(void) {
+  return FS_IOC_GETFLAGS;
+}
+
+unsigned int ioctl_fs_ioc_setflags(void) {
+  return FS_IOC_SETFLAGS;
+}
+
+unsigned int ioctl_blksszget(void) {
+  return BLKSSZGET;
+}
+
+unsigned int ioctl_iowr(void) {
+  return _IOWR('f', 3, int[6]);
+}
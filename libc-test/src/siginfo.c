@@ -0,0 +1,29 @@
+#include <signal.h>
+#include <string.h>
+
+// The SIGCHLD, timer and fault members of siginfo_t live in an anonymous
+// union in cygwin/signal.h. These functions fill them in through the C
+// definitions so the accessors in this package can be checked against them.
+
+size_t siginfo_size(void) {
+  return sizeof(siginfo_t);
+}
+
+void siginfo_set_sigchld(siginfo_t *si, int status, clock_t utime, clock_t stime) {
+  memset(si, 0, sizeof(*si));
+  si->si_status = status;
+  si->si_utime = utime;
+  si->si_stime = stime;
+}
+
+void siginfo_set_timer(siginfo_t *si, int value, timer_t tid, unsigned int overrun) {
+  memset(si, 0, sizeof(*si));
+  si->si_value.sival_int = value;
+  si->si_tid = tid;
+  si->si_overrun = overrun;
+}
+
+void siginfo_set_addr(siginfo_t *si, void *addr) {
+  memset(si, 0, sizeof(*si));
+  si->si_addr = addr;
+}
@@ -0,0 +1,63 @@
+//! Compare libc's siginfo_t accessors against the C union members
+
+extern crate libc;
+
+#[cfg(target_os = "cygwin")]
+mod t {
+    use libc::{self, clock_t, siginfo_t, size_t, timer_t};
+    use std::mem;
+
+    extern "C" {
+        pub fn siginfo_size() -> size_t;
+        pub fn siginfo_set_sigchld(
+            si: *mut siginfo_t,
+            status: libc::c_int,
+            utime: clock_t,
+            stime: clock_t,
+        );
+        pub fn siginfo_set_timer(
+            si: *mut siginfo_t,
+            value: libc::c_int,
+            tid: timer_t,
+            overrun: libc::c_uint,
+        );
+        pub fn siginfo_set_addr(si: *mut siginfo_t, addr: *mut libc::c_void);
+    }
+
+    #[test]
+    fn test_size() {
+        assert_eq!(mem::size_of::<siginfo_t>(), unsafe { siginfo_size() });
+    }
+
+    #[test]
+    fn test_sigchld() {
+        unsafe {
+            let mut si: siginfo_t = mem::zeroed();
+            siginfo_set_sigchld(&mut si, 0x1234, 0x5678, 0x9abc);
+            assert_eq!(si.si_status(), 0x1234);
+            assert_eq!(si.si_utime(), 0x5678);
+            assert_eq!(si.si_stime(), 0x9abc);
+        }
+    }
+
+    #[test]
+    fn test_timer() {
+        unsafe {
+            let mut si: siginfo_t = mem::zeroed();
+            siginfo_set_timer(&mut si, 0x1234, 0x5678, 0x9abc);
+            assert_eq!(si.si_value().sival_ptr as usize as libc::c_int, 0x1234);
+            assert_eq!(si.si_tid(), 0x5678);
+            assert_eq!(si.si_overrun(), 0x9abc);
+        }
+    }
+
+    #[test]
+    fn test_addr() {
+        unsafe {
+            let mut si: siginfo_t = mem::zeroed();
+            let addr = 0x1234 as *mut libc::c_void;
+            siginfo_set_addr(&mut si, addr);
+            assert_eq!(si.si_addr(), addr);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Compare libc's _IO/_IOR/_IOW/_IOWR functions against the C macros
+
+extern crate libc;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod t {
+    use libc::{self, c_int, c_long, c_uint, Ioctl};
+    use std::mem;
+
+    extern "C" {
+        pub fn ioctl_fs_ioc_getflags() -> c_uint;
+        pub fn ioctl_fs_ioc_setflags() -> c_uint;
+        pub fn ioctl_blksszget() -> c_uint;
+        pub fn ioctl_iowr() -> c_uint;
+    }
+
+    #[test]
+    fn test_ior() {
+        let req: Ioctl = libc::_IOR('f' as c_uint, 1, mem::size_of::<c_long>());
+        assert_eq!(req as c_uint, unsafe { ioctl_fs_ioc_getflags() });
+        assert_eq!(libc::_IOC_DIR(req), libc::_IOC_READ);
+        assert_eq!(libc::_IOC_TYPE(req), 'f' as c_uint);
+        assert_eq!(libc::_IOC_NR(req), 1);
+        assert_eq!(libc::_IOC_SIZE(req) as usize, mem::size_of::<c_long>());
+    }
+
+    #[test]
+    fn test_iow() {
+        let req: Ioctl = libc::_IOW('f' as c_uint, 2, mem::size_of::<c_long>());
+        assert_eq!(req as c_uint, unsafe { ioctl_fs_ioc_setflags() });
+        assert_eq!(libc::_IOC_DIR(req), libc::_IOC_WRITE);
+    }
+
+    #[test]
+    fn test_io() {
+        let req: Ioctl = libc::_IO(0x12, 104);
+        assert_eq!(req as c_uint, unsafe { ioctl_blksszget() });
+        assert_eq!(req, libc::BLKSSZGET as Ioctl);
+        assert_eq!(libc::_IOC_DIR(req), libc::_IOC_NONE);
+    }
+
+    #[test]
+    fn test_iowr() {
+        let req: Ioctl = libc::_IOWR('f' as c_uint, 3, mem::size_of::<[c_int; 6]>());
+        assert_eq!(req as c_uint, unsafe { ioctl_iowr() });
+        assert_eq!(libc::_IOC_DIR(req), libc::_IOC_READ | libc::_IOC_WRITE);
+    }
+}
@@ -606,8 +606,8 @@ s_no_extra_traits! {
         pub sigev_value: ::sigval,
         pub sigev_signo: ::c_int,
         pub sigev_notify: ::c_int,
-        __unused1: *mut ::c_void,       //actually a function pointer
-        pub sigev_notify_attributes: *mut pthread_attr_t
+        pub sigev_notify_function: ::Option<extern "C" fn(::sigval)>,
+        pub sigev_notify_attributes: *mut pthread_attr_t,
     }
 }
 
@@ -618,6 +618,8 @@ cfg_if! {
                 self.sigev_value == other.sigev_value
                     && self.sigev_signo == other.sigev_signo
                     && self.sigev_notify == other.sigev_notify
+                    && self.sigev_notify_function.map(|f| f as usize)
+                        == other.sigev_notify_function.map(|f| f as usize)
                     && self.sigev_notify_attributes
                         == other.sigev_notify_attributes
             }
@@ -631,6 +633,7 @@ cfg_if! {
                     .field("sigev_value", &self.sigev_value)
                     .field("sigev_signo", &self.sigev_signo)
                     .field("sigev_notify", &self.sigev_notify)
+                    .field("sigev_notify_function", &self.sigev_notify_function)
                     .field("sigev_notify_attributes",
                            &self.sigev_notify_attributes)
                     .finish()
@@ -642,57 +645,113 @@ cfg_if! {
                 self.sigev_value.hash(state);
                 self.sigev_signo.hash(state);
                 self.sigev_notify.hash(state);
+                self.sigev_notify_function.hash(state);
                 self.sigev_notify_attributes.hash(state);
             }
         }
     }
 }
 
-s_no_extra_traits! {
-    pub struct siginfo_t {
-        pub si_signo: ::c_int,
-        pub si_code: ::c_int,
-        pub si_pid: ::pid_t,
-        pub si_uid: ::uid_t,
-        pub si_errno: ::c_int,
-        #[doc(hidden)]
-        #[deprecated(
-            since="0.2.54",
-            note="Please leave a comment on \
-                  https://github.com/rust-lang/libc/pull/1316 if you're using \
-                  this field"
-        )]
-        pub __pad: [u32; 32],
-    }
-}
-
-impl siginfo_t {
-    pub unsafe fn si_addr(&self) -> *mut ::c_void {
+cfg_if! {
+    if #[cfg(libc_union)] {
+        // Internal, for access to the timer fields of the union
         #[repr(C)]
-        struct siginfo_si_addr {
-            _si_signo: ::c_int,
-            _si_code: ::c_int,
-            _si_pid: ::pid_t,
-            _si_uid: ::uid_t,
-            _si_errno: ::c_int,
-            si_addr: *mut ::c_void,
+        struct sifields_timer {
+            si_value: ::sigval,
+            si_tid: ::timer_t,
+            si_overrun: ::c_uint,
+        }
+        impl ::Copy for sifields_timer {}
+        impl ::Clone for sifields_timer {
+            fn clone(&self) -> sifields_timer {
+                *self
+            }
         }
-        (*(self as *const siginfo_t as *const siginfo_si_addr)).si_addr
-    }
 
-    pub unsafe fn si_status(&self) -> ::c_int {
+        // Internal, for access to the SIGCHLD fields of the union
         #[repr(C)]
-        struct siginfo_sigchld {
-            _si_signo: ::c_int,
-            _si_code: ::c_int,
-            _si_pid: ::pid_t,
-            _si_uid: ::uid_t,
-            _si_errno: ::c_int,
+        struct sifields_sigchld {
             si_status: ::c_int,
+            si_utime: ::clock_t,
+            si_stime: ::clock_t,
+        }
+        impl ::Copy for sifields_sigchld {}
+        impl ::Clone for sifields_sigchld {
+            fn clone(&self) -> sifields_sigchld {
+                *self
+            }
+        }
+
+        // Internal, the anonymous union at the end of siginfo_t
+        #[repr(C)]
+        union sifields {
+            __pad: [u32; 32],
+            timer: sifields_timer,
+            sigchld: sifields_sigchld,
+            si_addr: *mut ::c_void,
+        }
+        impl ::Copy for sifields {}
+        impl ::Clone for sifields {
+            fn clone(&self) -> sifields {
+                *self
+            }
+        }
+
+        s_no_extra_traits! {
+            pub struct siginfo_t {
+                pub si_signo: ::c_int,
+                pub si_code: ::c_int,
+                pub si_pid: ::pid_t,
+                pub si_uid: ::uid_t,
+                pub si_errno: ::c_int,
+                sifields: sifields,
+            }
+        }
+
+        impl siginfo_t {
+            pub unsafe fn si_addr(&self) -> *mut ::c_void {
+                self.sifields.si_addr
+            }
+
+            pub unsafe fn si_status(&self) -> ::c_int {
+                self.sifields.sigchld.si_status
+            }
+
+            pub unsafe fn si_utime(&self) -> ::clock_t {
+                self.sifields.sigchld.si_utime
+            }
+
+            pub unsafe fn si_stime(&self) -> ::clock_t {
+                self.sifields.sigchld.si_stime
+            }
+
+            pub unsafe fn si_value(&self) -> ::sigval {
+                self.sifields.timer.si_value
+            }
+
+            pub unsafe fn si_tid(&self) -> ::timer_t {
+                self.sifields.timer.si_tid
+            }
+
+            pub unsafe fn si_overrun(&self) -> ::c_uint {
+                self.sifields.timer.si_overrun
+            }
+        }
+    } else {
+        s_no_extra_traits! {
+            pub struct siginfo_t {
+                pub si_signo: ::c_int,
+                pub si_code: ::c_int,
+                pub si_pid: ::pid_t,
+                pub si_uid: ::uid_t,
+                pub si_errno: ::c_int,
+                sifields: [u64; 16],
+            }
         }
-        (*(self as *const siginfo_t as *const siginfo_sigchld)).si_status
     }
+}
 
+impl siginfo_t {
     pub unsafe fn si_pid(&self) -> ::pid_t {
         self.si_pid
     }
@@ -700,19 +759,6 @@ impl siginfo_t {
     pub unsafe fn si_uid(&self) -> ::uid_t {
         self.si_uid
     }
-
-    pub unsafe fn si_value(&self) -> ::sigval {
-        #[repr(C)]
-        struct siginfo_si_value {
-            _si_signo: ::c_int,
-            _si_code: ::c_int,
-            _si_pid: ::pid_t,
-            _si_uid: ::uid_t,
-            _si_errno: ::c_int,
-            si_value: ::sigval,
-        }
-        (*(self as *const siginfo_t as *const siginfo_si_value)).si_value
-    }
 }
 
 cfg_if! {
@@ -724,7 +770,7 @@ cfg_if! {
                     && self.si_pid == other.si_pid
                     && self.si_uid == other.si_uid
                     && self.si_errno == other.si_errno
-                    // Ignore __pad
+                    // Ignore sifields
             }
         }
 
@@ -738,7 +784,7 @@ cfg_if! {
                     .field("si_pid", &self.si_pid)
                     .field("si_uid", &self.si_uid)
                     .field("si_errno", &self.si_errno)
-                    // Ignore __pad
+                    // Ignore sifields
                     .finish()
             }
         }
@@ -750,7 +796,7 @@ cfg_if! {
                 self.si_pid.hash(state);
                 self.si_uid.hash(state);
                 self.si_errno.hash(state);
-                // Ignore __pad
+                // Ignore sifields
             }
         }
     }
@@ -1083,6 +1129,38 @@ extern "C" {
     pub fn endpwent();
 }
 
+// grp.h
+extern "C" {
+    pub fn getgrgid(gid: ::gid_t) -> *mut ::group;
+    pub fn getgrgid_r(
+        gid: ::gid_t,
+        grp: *mut ::group,
+        buf: *mut c_char,
+        buflen: ::size_t,
+        result: *mut *mut ::group,
+    ) -> ::c_int;
+    pub fn getgrnam(name: *const c_char) -> *mut ::group;
+    pub fn getgrnam_r(
+        name: *const c_char,
+        grp: *mut ::group,
+        buf: *mut c_char,
+        buflen: ::size_t,
+        result: *mut *mut ::group,
+    ) -> ::c_int;
+
+    pub fn getgrent() -> *mut ::group;
+    pub fn setgrent();
+    pub fn endgrent();
+
+    pub fn getgrouplist(
+        user: *const c_char,
+        group: ::gid_t,
+        groups: *mut ::gid_t,
+        ngroups: *mut ::c_int,
+    ) -> ::c_int;
+    pub fn initgroups(user: *const c_char, group: ::gid_t) -> ::c_int;
+}
+
 // cygwin/if.h
 pub const IFF_UP: ::c_int = 0x1; // interface is up
 pub const IFF_BROADCAST: ::c_int = 0x2; // broadcast address valid
@@ -1105,6 +1183,14 @@ s! {
 
 pub const IF_NAMESIZE: ::size_t = 44;
 pub const IFNAMSIZ: ::size_t = IF_NAMESIZE;
+pub const IFRF_FRIENDLYNAMESIZ: ::size_t = 260;
+
+s! {
+    pub struct ifreq_frndlyname {
+        pub ifrf_len: ::c_int,
+        pub ifrf_friendlyname: [c_char; IFRF_FRIENDLYNAMESIZ],
+    }
+}
 
 s_no_extra_traits! {
     #[cfg(libc_union)]
@@ -1118,6 +1204,7 @@ s_no_extra_traits! {
         pub ifru_metric: ::c_int,
         pub ifru_mtu: ::c_int,
         pub ifru_ifindex: ::c_int,
+        pub ifru_frndlyname: *mut ifreq_frndlyname,
         pub ifru_data: *mut c_char,
         __ifru_pad: [c_char; 28],
     }
@@ -1138,6 +1225,7 @@ cfg_if! {
                     .field("ifru_metric", unsafe { &self.ifru_metric })
                     .field("ifru_ifindex", unsafe { &self.ifru_ifindex })
                     .field("ifru_mtu", unsafe { &self.ifru_mtu })
+                    .field("ifru_frndlyname", unsafe { &self.ifru_frndlyname })
                     .field("ifru_data", unsafe { &self.ifru_data })
                     .finish()
             }
@@ -1156,6 +1244,7 @@ cfg_if! {
                     self.ifru_metric == other.ifru_metric &&
                     self.ifru_ifindex == other.ifru_ifindex &&
                     self.ifru_mtu == other.ifru_mtu &&
+                    self.ifru_frndlyname == other.ifru_frndlyname &&
                     self.ifru_data == other.ifru_data
                 }
             }
@@ -1176,6 +1265,7 @@ cfg_if! {
                 unsafe { self.ifru_metric.hash(state) };
                 unsafe { self.ifru_ifindex.hash(state) };
                 unsafe { self.ifru_mtu.hash(state) };
+                unsafe { self.ifru_frndlyname.hash(state) };
                 unsafe { self.ifru_data.hash(state) };
             }
         }
@@ -1283,6 +1373,24 @@ extern "C" {
     pub fn if_freenameindex(ptr: *mut if_nameindex);
 }
 
+// ifaddrs.h
+s! {
+    pub struct ifaddrs {
+        pub ifa_next: *mut ifaddrs,
+        pub ifa_name: *mut c_char,
+        pub ifa_flags: ::c_uint,
+        pub ifa_addr: *mut sockaddr,
+        pub ifa_netmask: *mut sockaddr,
+        pub ifa_dstaddr: *mut sockaddr,
+        pub ifa_data: *mut ::c_void,
+    }
+}
+
+extern "C" {
+    pub fn getifaddrs(ifap: *mut *mut ifaddrs) -> ::c_int;
+    pub fn freeifaddrs(ifa: *mut ifaddrs);
+}
+
 // asm/socket.h
 pub const FIONREAD: Ioctl = 0x4008667f;
 pub const FIONBIO: Ioctl = 0x8004667e;
@@ -1299,6 +1407,7 @@ pub const SIOCGIFMTU: c_ulong = 0x8050736b;
 pub const SIOCGIFINDEX: c_ulong = 0x8050736c;
 pub const SIOGIFINDEX: c_ulong = SIOCGIFINDEX;
 pub const SIOCGIFDSTADDR: c_ulong = 0x8050736e;
+pub const SIOCGIFFRNDLYNAM: c_ulong = 0x8050736f;
 pub const SOL_SOCKET: ::c_int = 0xffff;
 pub const SO_DEBUG: ::c_int = 1;
 pub const SO_ACCEPTCONN: ::c_int = 0x0002;
@@ -1561,6 +1670,7 @@ pub const IP_UNBLOCK_SOURCE: ::c_int = 18;
 pub const IP_PKTINFO: ::c_int = 19;
 pub const IP_UNICAST_IF: ::c_int = 31;
 pub const IPV6_HOPOPTS: ::c_int = 1;
+pub const IPV6_HDRINCL: ::c_int = 2;
 pub const IPV6_UNICAST_HOPS: ::c_int = 4;
 pub const IPV6_MULTICAST_IF: ::c_int = 9;
 pub const IPV6_MULTICAST_HOPS: ::c_int = 10;
@@ -2192,6 +2302,85 @@ extern "C" {
     pub fn uselocale(loc: ::locale_t) -> ::locale_t;
 }
 
+// langinfo.h
+pub const _NL_CTYPE_CODESET_NAME: ::nl_item = 0;
+pub const CODESET: ::nl_item = _NL_CTYPE_CODESET_NAME;
+pub const D_T_FMT: ::nl_item = 1;
+pub const D_FMT: ::nl_item = 2;
+pub const T_FMT: ::nl_item = 3;
+pub const T_FMT_AMPM: ::nl_item = 4;
+pub const AM_STR: ::nl_item = 5;
+pub const PM_STR: ::nl_item = 6;
+
+pub const DAY_1: ::nl_item = 7;
+pub const DAY_2: ::nl_item = 8;
+pub const DAY_3: ::nl_item = 9;
+pub const DAY_4: ::nl_item = 10;
+pub const DAY_5: ::nl_item = 11;
+pub const DAY_6: ::nl_item = 12;
+pub const DAY_7: ::nl_item = 13;
+
+pub const ABDAY_1: ::nl_item = 14;
+pub const ABDAY_2: ::nl_item = 15;
+pub const ABDAY_3: ::nl_item = 16;
+pub const ABDAY_4: ::nl_item = 17;
+pub const ABDAY_5: ::nl_item = 18;
+pub const ABDAY_6: ::nl_item = 19;
+pub const ABDAY_7: ::nl_item = 20;
+
+pub const MON_1: ::nl_item = 21;
+pub const MON_2: ::nl_item = 22;
+pub const MON_3: ::nl_item = 23;
+pub const MON_4: ::nl_item = 24;
+pub const MON_5: ::nl_item = 25;
+pub const MON_6: ::nl_item = 26;
+pub const MON_7: ::nl_item = 27;
+pub const MON_8: ::nl_item = 28;
+pub const MON_9: ::nl_item = 29;
+pub const MON_10: ::nl_item = 30;
+pub const MON_11: ::nl_item = 31;
+pub const MON_12: ::nl_item = 32;
+
+pub const ABMON_1: ::nl_item = 33;
+pub const ABMON_2: ::nl_item = 34;
+pub const ABMON_3: ::nl_item = 35;
+pub const ABMON_4: ::nl_item = 36;
+pub const ABMON_5: ::nl_item = 37;
+pub const ABMON_6: ::nl_item = 38;
+pub const ABMON_7: ::nl_item = 39;
+pub const ABMON_8: ::nl_item = 40;
+pub const ABMON_9: ::nl_item = 41;
+pub const ABMON_10: ::nl_item = 42;
+pub const ABMON_11: ::nl_item = 43;
+pub const ABMON_12: ::nl_item = 44;
+
+pub const ERA: ::nl_item = 45;
+pub const ERA_D_FMT: ::nl_item = 46;
+pub const ERA_D_T_FMT: ::nl_item = 47;
+pub const ERA_T_FMT: ::nl_item = 48;
+pub const ALT_DIGITS: ::nl_item = 49;
+
+pub const RADIXCHAR: ::nl_item = 50;
+pub const THOUSEP: ::nl_item = 51;
+
+pub const YESEXPR: ::nl_item = 52;
+pub const NOEXPR: ::nl_item = 53;
+pub const YESSTR: ::nl_item = 54;
+pub const NOSTR: ::nl_item = 55;
+
+pub const CRNCYSTR: ::nl_item = 56;
+pub const D_MD_ORDER: ::nl_item = 57;
+
+pub const _NL_TIME_DATE_FMT: ::nl_item = 84;
+pub const _DATE_FMT: ::nl_item = _NL_TIME_DATE_FMT;
+pub const _NL_CTYPE_MB_CUR_MAX: ::nl_item = 85;
+pub const _NL_MESSAGES_CODESET: ::nl_item = 86;
+
+extern "C" {
+    pub fn nl_langinfo(item: ::nl_item) -> *mut c_char;
+    pub fn nl_langinfo_l(item: ::nl_item, locale: ::locale_t) -> *mut c_char;
+}
+
 // semaphore.h
 #[cfg_attr(feature = "extra_traits", derive(Debug))]
 pub enum sem {}
@@ -2213,6 +2402,11 @@ extern "C" {
     pub fn sem_close(sem: *mut sem_t) -> ::c_int;
     pub fn sem_unlink(name: *const c_char) -> ::c_int;
     pub fn sem_timedwait(sem: *mut sem_t, abstime: *const ::timespec) -> ::c_int;
+    pub fn sem_clockwait(
+        sem: *mut sem_t,
+        clock_id: clockid_t,
+        abstime: *const ::timespec,
+    ) -> ::c_int;
     pub fn sem_getvalue(sem: *mut sem_t, sval: *mut ::c_int) -> ::c_int;
 }
 
@@ -2292,6 +2486,196 @@ cfg_if! {
     }
 }
 
+// sys/vfs.h
+s! {
+    pub struct statfs {
+        pub f_type: ::c_long,
+        pub f_bsize: ::c_long,
+        pub f_blocks: ::c_long,
+        pub f_bfree: ::c_long,
+        pub f_bavail: ::c_long,
+        pub f_files: ::c_long,
+        pub f_ffree: ::c_long,
+        pub f_fsid: ::c_long,
+        pub f_namelen: ::c_long,
+        pub f_spare: [::c_long; 6],
+    }
+}
+
+extern "C" {
+    pub fn statfs(path: *const c_char, buf: *mut statfs) -> ::c_int;
+    pub fn fstatfs(fd: ::c_int, buf: *mut statfs) -> ::c_int;
+}
+
+// sys/xattr.h
+pub const XATTR_CREATE: ::c_int = 1;
+pub const XATTR_REPLACE: ::c_int = 2;
+
+extern "C" {
+    pub fn getxattr(
+        path: *const c_char,
+        name: *const c_char,
+        value: *mut ::c_void,
+        size: ::size_t,
+    ) -> ::ssize_t;
+    pub fn lgetxattr(
+        path: *const c_char,
+        name: *const c_char,
+        value: *mut ::c_void,
+        size: ::size_t,
+    ) -> ::ssize_t;
+    pub fn fgetxattr(
+        filedes: ::c_int,
+        name: *const c_char,
+        value: *mut ::c_void,
+        size: ::size_t,
+    ) -> ::ssize_t;
+    pub fn setxattr(
+        path: *const c_char,
+        name: *const c_char,
+        value: *const ::c_void,
+        size: ::size_t,
+        flags: ::c_int,
+    ) -> ::c_int;
+    pub fn lsetxattr(
+        path: *const c_char,
+        name: *const c_char,
+        value: *const ::c_void,
+        size: ::size_t,
+        flags: ::c_int,
+    ) -> ::c_int;
+    pub fn fsetxattr(
+        filedes: ::c_int,
+        name: *const c_char,
+        value: *const ::c_void,
+        size: ::size_t,
+        flags: ::c_int,
+    ) -> ::c_int;
+    pub fn listxattr(path: *const c_char, list: *mut c_char, size: ::size_t) -> ::ssize_t;
+    pub fn llistxattr(path: *const c_char, list: *mut c_char, size: ::size_t) -> ::ssize_t;
+    pub fn flistxattr(filedes: ::c_int, list: *mut c_char, size: ::size_t) -> ::ssize_t;
+    pub fn removexattr(path: *const c_char, name: *const c_char) -> ::c_int;
+    pub fn lremovexattr(path: *const c_char, name: *const c_char) -> ::c_int;
+    pub fn fremovexattr(filedes: ::c_int, name: *const c_char) -> ::c_int;
+}
+
+// cygwin/acl.h
+s! {
+    pub struct aclent_t {
+        pub a_type: ::c_int,
+        pub a_id: ::id_t,
+        pub a_perm: ::mode_t,
+    }
+}
+
+pub const SETACL: ::c_int = 0x0;
+pub const GETACL: ::c_int = 0x1;
+pub const GETACLCNT: ::c_int = 0x2;
+
+pub const USER_OBJ: ::c_int = 0x0001;
+pub const USER: ::c_int = 0x0002;
+pub const GROUP_OBJ: ::c_int = 0x0004;
+pub const GROUP: ::c_int = 0x0008;
+pub const CLASS_OBJ: ::c_int = 0x0010;
+pub const OTHER_OBJ: ::c_int = 0x0020;
+pub const ACL_DEFAULT: ::c_int = 0x1000;
+pub const DEF_USER_OBJ: ::c_int = ACL_DEFAULT | USER_OBJ;
+pub const DEF_USER: ::c_int = ACL_DEFAULT | USER;
+pub const DEF_GROUP_OBJ: ::c_int = ACL_DEFAULT | GROUP_OBJ;
+pub const DEF_GROUP: ::c_int = ACL_DEFAULT | GROUP;
+pub const DEF_CLASS_OBJ: ::c_int = ACL_DEFAULT | CLASS_OBJ;
+pub const DEF_OTHER_OBJ: ::c_int = ACL_DEFAULT | OTHER_OBJ;
+
+pub const GRP_ERROR: ::c_int = 0x1;
+pub const USER_ERROR: ::c_int = 0x2;
+pub const CLASS_ERROR: ::c_int = 0x3;
+pub const OTHER_ERROR: ::c_int = 0x4;
+pub const DUPLICATE_ERROR: ::c_int = 0x5;
+pub const ENTRY_ERROR: ::c_int = 0x6;
+pub const MISS_ERROR: ::c_int = 0x7;
+pub const MEM_ERROR: ::c_int = 0x8;
+
+extern "C" {
+    pub fn acl(
+        path: *const c_char,
+        cmd: ::c_int,
+        nentries: ::c_int,
+        aclbufp: *mut aclent_t,
+    ) -> ::c_int;
+    pub fn facl(fd: ::c_int, cmd: ::c_int, nentries: ::c_int, aclbufp: *mut aclent_t) -> ::c_int;
+    pub fn aclcheck(aclbufp: *mut aclent_t, nentries: ::c_int, which: *mut ::c_int) -> ::c_int;
+    pub fn aclsort(nentries: ::c_int, calclass: ::c_int, aclbufp: *mut aclent_t) -> ::c_int;
+    pub fn acltomode(aclbufp: *mut aclent_t, nentries: ::c_int, modep: *mut ::mode_t) -> ::c_int;
+    pub fn aclfrommode(aclbufp: *mut aclent_t, nentries: ::c_int, modep: *mut ::mode_t) -> ::c_int;
+    pub fn acltopbits(aclbufp: *mut aclent_t, nentries: ::c_int, pbitsp: *mut ::mode_t) -> ::c_int;
+    pub fn aclfrompbits(
+        aclbufp: *mut aclent_t,
+        nentries: ::c_int,
+        pbitsp: *mut ::mode_t,
+    ) -> ::c_int;
+    pub fn acltotext(aclbufp: *mut aclent_t, aclcnt: ::c_int) -> *mut c_char;
+    pub fn aclfromtext(acltextp: *mut c_char, aclcnt: *mut ::c_int) -> *mut aclent_t;
+}
+
+// sys/acl.h
+pub type acl_perm_t = u32;
+pub type acl_type_t = u32;
+pub type acl_tag_t = u32;
+pub type acl_t = *mut ::c_void;
+pub type acl_entry_t = u64;
+pub type acl_permset_t = u64;
+
+pub const ACL_READ: acl_perm_t = 0x4;
+pub const ACL_WRITE: acl_perm_t = 0x2;
+pub const ACL_EXECUTE: acl_perm_t = 0x1;
+
+pub const ACL_UNDEFINED_TAG: acl_tag_t = 0x0000;
+pub const ACL_USER_OBJ: acl_tag_t = USER_OBJ as acl_tag_t;
+pub const ACL_USER: acl_tag_t = USER as acl_tag_t;
+pub const ACL_GROUP_OBJ: acl_tag_t = GROUP_OBJ as acl_tag_t;
+pub const ACL_GROUP: acl_tag_t = GROUP as acl_tag_t;
+pub const ACL_MASK: acl_tag_t = CLASS_OBJ as acl_tag_t;
+pub const ACL_OTHER: acl_tag_t = OTHER_OBJ as acl_tag_t;
+
+pub const ACL_FIRST_ENTRY: ::c_int = 0;
+pub const ACL_NEXT_ENTRY: ::c_int = 1;
+
+pub const ACL_TYPE_ACCESS: acl_type_t = 0x0;
+pub const ACL_TYPE_DEFAULT: acl_type_t = 0x1;
+
+pub const ACL_UNDEFINED_ID: ::id_t = !0;
+
+extern "C" {
+    pub fn acl_add_perm(permset_d: acl_permset_t, perm: acl_perm_t) -> ::c_int;
+    pub fn acl_calc_mask(acl_p: *mut acl_t) -> ::c_int;
+    pub fn acl_clear_perms(permset_d: acl_permset_t) -> ::c_int;
+    pub fn acl_copy_entry(dest_d: acl_entry_t, src_d: acl_entry_t) -> ::c_int;
+    pub fn acl_copy_ext(buf_p: *mut ::c_void, acl: acl_t, size: ::ssize_t) -> ::ssize_t;
+    pub fn acl_copy_int(buf_p: *const ::c_void) -> acl_t;
+    pub fn acl_create_entry(acl_p: *mut acl_t, entry_p: *mut acl_entry_t) -> ::c_int;
+    pub fn acl_delete_def_file(path_p: *const c_char) -> ::c_int;
+    pub fn acl_delete_entry(acl: acl_t, entry_d: acl_entry_t) -> ::c_int;
+    pub fn acl_delete_perm(permset_d: acl_permset_t, perm: acl_perm_t) -> ::c_int;
+    pub fn acl_dup(acl: acl_t) -> acl_t;
+    pub fn acl_free(obj_p: *mut ::c_void) -> ::c_int;
+    pub fn acl_from_text(buf_p: *const c_char) -> acl_t;
+    pub fn acl_get_entry(acl: acl_t, entry_id: ::c_int, entry_p: *mut acl_entry_t) -> ::c_int;
+    pub fn acl_get_fd(fd: ::c_int) -> acl_t;
+    pub fn acl_get_file(path_p: *const c_char, type_: acl_type_t) -> acl_t;
+    pub fn acl_get_permset(entry_d: acl_entry_t, permset_p: *mut acl_permset_t) -> ::c_int;
+    pub fn acl_get_qualifier(entry_d: acl_entry_t) -> *mut ::c_void;
+    pub fn acl_get_tag_type(entry_d: acl_entry_t, tag_type_p: *mut acl_tag_t) -> ::c_int;
+    pub fn acl_init(count: ::c_int) -> acl_t;
+    pub fn acl_set_fd(fd: ::c_int, acl: acl_t) -> ::c_int;
+    pub fn acl_set_file(path_p: *const c_char, type_: acl_type_t, acl: acl_t) -> ::c_int;
+    pub fn acl_set_permset(entry_d: acl_entry_t, permset_d: acl_permset_t) -> ::c_int;
+    pub fn acl_set_qualifier(entry_d: acl_entry_t, qualifier_p: *const ::c_void) -> ::c_int;
+    pub fn acl_set_tag_type(entry_d: acl_entry_t, tag_type: acl_tag_t) -> ::c_int;
+    pub fn acl_size(acl: acl_t) -> ::ssize_t;
+    pub fn acl_to_text(acl: acl_t, len_p: *mut ::ssize_t) -> *mut c_char;
+    pub fn acl_valid(acl: acl_t) -> ::c_int;
+}
+
 // sys/termios.h
 pub const TIOCMGET: Ioctl = 0x5415;
 pub const TIOCMBIS: Ioctl = 0x5416;
@@ -2474,6 +2858,192 @@ pub const TIOCLINUX: Ioctl = 0x5403;
 pub const TIOCGPGRP: Ioctl = 0x540f;
 pub const TIOCSPGRP: Ioctl = 0x5410;
 
+// pty.h
+extern "C" {
+    pub fn openpty(
+        amaster: *mut ::c_int,
+        aslave: *mut ::c_int,
+        name: *mut c_char,
+        termp: *const termios,
+        winp: *const ::winsize,
+    ) -> ::c_int;
+
+    pub fn forkpty(
+        amaster: *mut ::c_int,
+        name: *mut c_char,
+        termp: *const termios,
+        winp: *const ::winsize,
+    ) -> ::pid_t;
+}
+
+// utmp.h
+pub const UT_LINESIZE: usize = 16;
+pub const UT_NAMESIZE: usize = 16;
+pub const UT_HOSTSIZE: usize = 256;
+pub const UT_IDLEN: usize = 2;
+
+pub const RUN_LVL: ::c_short = 1;
+pub const BOOT_TIME: ::c_short = 2;
+pub const NEW_TIME: ::c_short = 3;
+pub const OLD_TIME: ::c_short = 4;
+pub const INIT_PROCESS: ::c_short = 5;
+pub const LOGIN_PROCESS: ::c_short = 6;
+pub const USER_PROCESS: ::c_short = 7;
+pub const DEAD_PROCESS: ::c_short = 8;
+
+s_no_extra_traits! {
+    pub struct utmp {
+        pub ut_type: ::c_short,
+        pub ut_pid: ::pid_t,
+        pub ut_line: [::c_char; UT_LINESIZE],
+        pub ut_id: [::c_char; UT_IDLEN],
+        pub ut_time: ::time_t,
+        pub ut_user: [::c_char; UT_NAMESIZE],
+        pub ut_host: [::c_char; UT_HOSTSIZE],
+        pub ut_addr: ::c_long,
+    }
+
+    pub struct utmpx {
+        pub ut_type: ::c_short,
+        pub ut_pid: ::pid_t,
+        pub ut_line: [::c_char; UT_LINESIZE],
+        pub ut_id: [::c_char; UT_IDLEN],
+        pub ut_time: ::time_t,
+        pub ut_user: [::c_char; UT_NAMESIZE],
+        pub ut_host: [::c_char; UT_HOSTSIZE],
+        pub ut_addr: ::c_long,
+        pub ut_tv: ::timeval,
+    }
+}
+
+cfg_if! {
+    if #[cfg(feature = "extra_traits")] {
+        impl PartialEq for utmp {
+            fn eq(&self, other: &utmp) -> bool {
+                self.ut_type == other.ut_type
+                    && self.ut_pid == other.ut_pid
+                    && self.ut_line == other.ut_line
+                    && self.ut_id == other.ut_id
+                    && self.ut_time == other.ut_time
+                    && self.ut_user == other.ut_user
+                    && self
+                    .ut_host
+                    .iter()
+                    .zip(other.ut_host.iter())
+                    .all(|(a,b)| a == b)
+                    && self.ut_addr == other.ut_addr
+            }
+        }
+
+        impl Eq for utmp {}
+
+        impl ::fmt::Debug for utmp {
+            fn fmt(&self, f: &mut ::fmt::Formatter) -> ::fmt::Result {
+                f.debug_struct("utmp")
+                    .field("ut_type", &self.ut_type)
+                    .field("ut_pid", &self.ut_pid)
+                    .field("ut_line", &self.ut_line)
+                    .field("ut_id", &self.ut_id)
+                    .field("ut_time", &self.ut_time)
+                    .field("ut_user", &self.ut_user)
+                // FIXME: .field("ut_host", &self.ut_host)
+                    .field("ut_addr", &self.ut_addr)
+                    .finish()
+            }
+        }
+
+        impl ::hash::Hash for utmp {
+            fn hash<H: ::hash::Hasher>(&self, state: &mut H) {
+                self.ut_type.hash(state);
+                self.ut_pid.hash(state);
+                self.ut_line.hash(state);
+                self.ut_id.hash(state);
+                self.ut_time.hash(state);
+                self.ut_user.hash(state);
+                self.ut_host.hash(state);
+                self.ut_addr.hash(state);
+            }
+        }
+
+        impl PartialEq for utmpx {
+            fn eq(&self, other: &utmpx) -> bool {
+                self.ut_type == other.ut_type
+                    && self.ut_pid == other.ut_pid
+                    && self.ut_line == other.ut_line
+                    && self.ut_id == other.ut_id
+                    && self.ut_time == other.ut_time
+                    && self.ut_user == other.ut_user
+                    && self
+                    .ut_host
+                    .iter()
+                    .zip(other.ut_host.iter())
+                    .all(|(a,b)| a == b)
+                    && self.ut_addr == other.ut_addr
+                    && self.ut_tv == other.ut_tv
+            }
+        }
+
+        impl Eq for utmpx {}
+
+        impl ::fmt::Debug for utmpx {
+            fn fmt(&self, f: &mut ::fmt::Formatter) -> ::fmt::Result {
+                f.debug_struct("utmpx")
+                    .field("ut_type", &self.ut_type)
+                    .field("ut_pid", &self.ut_pid)
+                    .field("ut_line", &self.ut_line)
+                    .field("ut_id", &self.ut_id)
+                    .field("ut_time", &self.ut_time)
+                    .field("ut_user", &self.ut_user)
+                // FIXME: .field("ut_host", &self.ut_host)
+                    .field("ut_addr", &self.ut_addr)
+                    .field("ut_tv", &self.ut_tv)
+                    .finish()
+            }
+        }
+
+        impl ::hash::Hash for utmpx {
+            fn hash<H: ::hash::Hasher>(&self, state: &mut H) {
+                self.ut_type.hash(state);
+                self.ut_pid.hash(state);
+                self.ut_line.hash(state);
+                self.ut_id.hash(state);
+                self.ut_time.hash(state);
+                self.ut_user.hash(state);
+                self.ut_host.hash(state);
+                self.ut_addr.hash(state);
+                self.ut_tv.hash(state);
+            }
+        }
+    }
+}
+
+extern "C" {
+    pub fn endutent();
+    pub fn getutent() -> *mut utmp;
+    pub fn getutid(id: *const utmp) -> *mut utmp;
+    pub fn getutline(line: *const utmp) -> *mut utmp;
+    pub fn pututline(ut: *const utmp) -> *mut utmp;
+    pub fn setutent();
+    pub fn utmpname(file: *const ::c_char) -> ::c_int;
+    pub fn login(ut: *const utmp);
+    pub fn logout(line: *const ::c_char) -> ::c_int;
+    pub fn login_tty(fd: ::c_int) -> ::c_int;
+    pub fn updwtmp(file: *const ::c_char, ut: *const utmp);
+    pub fn logwtmp(line: *const ::c_char, name: *const ::c_char, host: *const ::c_char);
+}
+
+// utmpx.h
+extern "C" {
+    pub fn endutxent();
+    pub fn getutxent() -> *mut utmpx;
+    pub fn getutxid(id: *const utmpx) -> *mut utmpx;
+    pub fn getutxline(line: *const utmpx) -> *mut utmpx;
+    pub fn pututxline(utmpx: *const utmpx) -> *mut utmpx;
+    pub fn setutxent();
+    pub fn utmpxname(file: *const ::c_char) -> ::c_int;
+    pub fn updwtmpx(file: *const ::c_char, utmpx: *const utmpx);
+}
+
 // cygwin/wait.h
 pub const WNOHANG: ::c_int = 1;
 pub const WUNTRACED: ::c_int = 2;
@@ -2513,6 +3083,17 @@ safe_f! {
     }
 }
 
+// sys/wait.h
+extern "C" {
+    pub fn wait3(status: *mut ::c_int, options: ::c_int, rusage: *mut ::rusage) -> ::pid_t;
+    pub fn wait4(
+        pid: ::pid_t,
+        status: *mut ::c_int,
+        options: ::c_int,
+        rusage: *mut ::rusage,
+    ) -> ::pid_t;
+}
+
 // cygwin/stdlib.h
 extern "C" {
     pub fn clearenv() -> ::c_int;
@@ -2597,6 +3178,226 @@ extern "C" {
     pub fn shm_unlink(name: *const c_char) -> ::c_int;
 }
 
+// cygwin/ipc.h
+s! {
+    pub struct ipc_perm {
+        pub uid: ::uid_t,
+        pub gid: ::gid_t,
+        pub cuid: ::uid_t,
+        pub cgid: ::gid_t,
+        pub mode: ::mode_t,
+        pub seq: ::c_ushort,
+        pub key: key_t,
+    }
+}
+
+pub const IPC_CREAT: ::c_int = 0x0200;
+pub const IPC_EXCL: ::c_int = 0x0400;
+pub const IPC_NOWAIT: ::c_int = 0x0800;
+pub const IPC_PRIVATE: key_t = 0;
+pub const IPC_RMID: ::c_int = 0x1000;
+pub const IPC_SET: ::c_int = 0x1001;
+pub const IPC_STAT: ::c_int = 0x1002;
+
+extern "C" {
+    pub fn ftok(pathname: *const ::c_char, proj_id: ::c_int) -> key_t;
+}
+
+// cygwin/msg.h
+pub type msgqnum_t = ::c_ulong;
+pub type msglen_t = ::c_ulong;
+
+s! {
+    pub struct msqid_ds {
+        pub msg_perm: ipc_perm,
+        pub msg_cbytes: msglen_t,
+        pub msg_qnum: msgqnum_t,
+        pub msg_qbytes: msglen_t,
+        pub msg_lspid: ::pid_t,
+        pub msg_lrpid: ::pid_t,
+        pub msg_stim: ::timespec,
+        pub msg_rtim: ::timespec,
+        pub msg_ctim: ::timespec,
+        msg_spare4: [::c_long; 2],
+    }
+}
+
+pub const MSG_NOERROR: ::c_int = 0x01000;
+
+extern "C" {
+    pub fn msgctl(msqid: ::c_int, cmd: ::c_int, buf: *mut msqid_ds) -> ::c_int;
+    pub fn msgget(key: key_t, msgflg: ::c_int) -> ::c_int;
+    pub fn msgrcv(
+        msqid: ::c_int,
+        msgp: *mut ::c_void,
+        msgsz: ::size_t,
+        msgtyp: ::c_long,
+        msgflg: ::c_int,
+    ) -> ::ssize_t;
+    pub fn msgsnd(
+        msqid: ::c_int,
+        msgp: *const ::c_void,
+        msgsz: ::size_t,
+        msgflg: ::c_int,
+    ) -> ::c_int;
+}
+
+// cygwin/sem.h
+s! {
+    pub struct semid_ds {
+        pub sem_perm: ipc_perm,
+        pub sem_nsems: ::c_ushort,
+        pub sem_otime: ::timespec,
+        pub sem_ctime: ::timespec,
+        sem_spare4: [::c_long; 2],
+    }
+
+    pub struct sembuf {
+        pub sem_num: ::c_ushort,
+        pub sem_op: ::c_short,
+        pub sem_flg: ::c_short,
+    }
+}
+
+pub const SEM_UNDO: ::c_int = 0x4000;
+
+pub const GETALL: ::c_int = 0x3000;
+pub const GETNCNT: ::c_int = 0x3001;
+pub const GETPID: ::c_int = 0x3002;
+pub const GETVAL: ::c_int = 0x3003;
+pub const GETZCNT: ::c_int = 0x3004;
+pub const SETALL: ::c_int = 0x3005;
+pub const SETVAL: ::c_int = 0x3006;
+
+extern "C" {
+    pub fn semctl(semid: ::c_int, semnum: ::c_int, cmd: ::c_int, ...) -> ::c_int;
+    pub fn semget(key: key_t, nsems: ::c_int, semflg: ::c_int) -> ::c_int;
+    pub fn semop(semid: ::c_int, sops: *mut sembuf, nsops: ::size_t) -> ::c_int;
+}
+
+// cygwin/shm.h
+pub type shmatt_t = ::c_uint;
+
+s! {
+    pub struct shmid_ds {
+        pub shm_perm: ipc_perm,
+        pub shm_segsz: ::size_t,
+        pub shm_lpid: ::pid_t,
+        pub shm_cpid: ::pid_t,
+        pub shm_nattch: shmatt_t,
+        pub shm_atim: ::timespec,
+        pub shm_dtim: ::timespec,
+        pub shm_ctim: ::timespec,
+        shm_spare4: [::c_long; 2],
+    }
+}
+
+pub const SHM_RDONLY: ::c_int = 0x01000;
+pub const SHM_RND: ::c_int = 0x02000;
+
+extern "C" {
+    pub fn shmat(shmid: ::c_int, shmaddr: *const ::c_void, shmflg: ::c_int) -> *mut ::c_void;
+    pub fn shmctl(shmid: ::c_int, cmd: ::c_int, buf: *mut shmid_ds) -> ::c_int;
+    pub fn shmdt(shmaddr: *const ::c_void) -> ::c_int;
+    pub fn shmget(key: key_t, size: ::size_t, shmflg: ::c_int) -> ::c_int;
+}
+
+// mqueue.h
+pub type mqd_t = ::intptr_t;
+
+s! {
+    pub struct mq_attr {
+        pub mq_flags: ::c_long,
+        pub mq_maxmsg: ::c_long,
+        pub mq_msgsize: ::c_long,
+        pub mq_curmsgs: ::c_long,
+    }
+}
+
+extern "C" {
+    pub fn mq_close(mqd: mqd_t) -> ::c_int;
+    pub fn mq_getattr(mqd: mqd_t, attr: *mut mq_attr) -> ::c_int;
+    pub fn mq_notify(mqd: mqd_t, notification: *const ::sigevent) -> ::c_int;
+    pub fn mq_open(name: *const ::c_char, oflag: ::c_int, ...) -> mqd_t;
+    pub fn mq_receive(
+        mqd: mqd_t,
+        msg_ptr: *mut ::c_char,
+        msg_len: ::size_t,
+        msg_prio: *mut ::c_uint,
+    ) -> ::ssize_t;
+    pub fn mq_send(
+        mqd: mqd_t,
+        msg_ptr: *const ::c_char,
+        msg_len: ::size_t,
+        msg_prio: ::c_uint,
+    ) -> ::c_int;
+    pub fn mq_setattr(mqd: mqd_t, newattr: *const mq_attr, oldattr: *mut mq_attr) -> ::c_int;
+    pub fn mq_timedreceive(
+        mqd: mqd_t,
+        msg_ptr: *mut ::c_char,
+        msg_len: ::size_t,
+        msg_prio: *mut ::c_uint,
+        abs_timeout: *const ::timespec,
+    ) -> ::ssize_t;
+    pub fn mq_timedsend(
+        mqd: mqd_t,
+        msg_ptr: *const ::c_char,
+        msg_len: ::size_t,
+        msg_prio: ::c_uint,
+        abs_timeout: *const ::timespec,
+    ) -> ::c_int;
+    pub fn mq_unlink(name: *const ::c_char) -> ::c_int;
+}
+
+// aio.h
+s! {
+    pub struct aiocb {
+        pub aio_lio_opcode: ::c_int,
+        pub aio_reqprio: ::c_int,
+        pub aio_fildes: ::c_int,
+        pub aio_buf: *mut ::c_void,
+        pub aio_nbytes: ::size_t,
+        pub aio_offset: off_t,
+        pub aio_sigevent: ::sigevent,
+        // the rest is private to Cygwin: the result, errno and the
+        // overlapped I/O state of the pending Windows request
+        __aio_rbytes: ::ssize_t,
+        __aio_errno: ::c_int,
+        __aio_wincb: [u64; 3],
+    }
+}
+
+pub const AIO_ALLDONE: ::c_int = 0;
+pub const AIO_CANCELED: ::c_int = 1;
+pub const AIO_NOTCANCELED: ::c_int = 2;
+
+pub const LIO_NOWAIT: ::c_int = 0;
+pub const LIO_WAIT: ::c_int = 1;
+
+pub const LIO_NOP: ::c_int = 0;
+pub const LIO_READ: ::c_int = 1;
+pub const LIO_WRITE: ::c_int = 2;
+
+extern "C" {
+    pub fn aio_cancel(fd: ::c_int, aiocbp: *mut aiocb) -> ::c_int;
+    pub fn aio_error(aiocbp: *const aiocb) -> ::c_int;
+    pub fn aio_fsync(op: ::c_int, aiocbp: *mut aiocb) -> ::c_int;
+    pub fn aio_read(aiocbp: *mut aiocb) -> ::c_int;
+    pub fn aio_return(aiocbp: *mut aiocb) -> ::ssize_t;
+    pub fn aio_suspend(
+        aiocb_list: *const *const aiocb,
+        nitems: ::c_int,
+        timeout: *const ::timespec,
+    ) -> ::c_int;
+    pub fn aio_write(aiocbp: *mut aiocb) -> ::c_int;
+    pub fn lio_listio(
+        mode: ::c_int,
+        aiocb_list: *const *mut aiocb,
+        nitems: ::c_int,
+        sevp: *mut ::sigevent,
+    ) -> ::c_int;
+}
+
 // strings.h
 extern "C" {
     pub fn explicit_bzero(s: *mut ::c_void, len: ::size_t);
@@ -2734,6 +3535,7 @@ extern "C" {
     pub fn pipe2(fds: *mut ::c_int, flags: ::c_int) -> ::c_int;
     pub fn sbrk(increment: ::intptr_t) -> *mut ::c_void;
     pub fn setgroups(ngroups: ::c_int, ptr: *const ::gid_t) -> ::c_int;
+    pub fn getlogin_r(name: *mut c_char, namesize: ::size_t) -> ::c_int;
     pub fn sethostname(name: *const c_char, len: ::size_t) -> ::c_int;
     pub fn vhangup() -> ::c_int;
     pub fn getdtablesize() -> ::c_int;
@@ -2935,7 +3737,7 @@ pub const O_EXCL: ::c_int = 0x0800;
 pub const O_SYNC: ::c_int = 0x2000;
 pub const O_NONBLOCK: ::c_int = 0x4000;
 pub const O_NOCTTY: ::c_int = 0x8000;
-pub const O_CLOEXEC: ::c_int = 0x40000;
+pub const O_CLOEXEC: ::c_int = _FNOINHERIT;
 pub const O_NOFOLLOW: ::c_int = 0x100000;
 pub const O_DIRECTORY: ::c_int = 0x200000;
 pub const O_EXEC: ::c_int = 0x400000;
@@ -2946,6 +3748,12 @@ pub const O_RSYNC: ::c_int = 0x2000;
 pub const O_TMPFILE: ::c_int = 0x800000;
 pub const O_NOATIME: ::c_int = 0x1000000;
 pub const O_PATH: ::c_int = 0x2000000;
+pub const _FBINARY: ::c_int = 0x10000;
+pub const _FTEXT: ::c_int = 0x20000;
+pub const _FNOINHERIT: ::c_int = 0x40000;
+pub const O_BINARY: ::c_int = _FBINARY;
+pub const O_TEXT: ::c_int = _FTEXT;
+pub const O_NOINHERIT: ::c_int = _FNOINHERIT;
 pub const F_DUPFD: ::c_int = 0;
 pub const F_GETFD: ::c_int = 1;
 pub const F_SETFD: ::c_int = 2;
@@ -2974,6 +3782,24 @@ pub const LOCK_SH: ::c_int = 1;
 pub const LOCK_EX: ::c_int = 2;
 pub const LOCK_NB: ::c_int = 4;
 pub const LOCK_UN: ::c_int = 8;
+pub const POSIX_FADV_NORMAL: ::c_int = 0;
+pub const POSIX_FADV_SEQUENTIAL: ::c_int = 1;
+pub const POSIX_FADV_RANDOM: ::c_int = 2;
+pub const POSIX_FADV_WILLNEED: ::c_int = 3;
+pub const POSIX_FADV_DONTNEED: ::c_int = 4;
+pub const POSIX_FADV_NOREUSE: ::c_int = 5;
+
+extern "C" {
+    pub fn posix_fadvise(fd: ::c_int, offset: off_t, len: off_t, advise: ::c_int) -> ::c_int;
+    pub fn posix_fallocate(fd: ::c_int, offset: off_t, len: off_t) -> ::c_int;
+}
+
+// io.h
+// `_get_osfhandle` and `_setmode` are macros for these exported functions.
+extern "C" {
+    pub fn get_osfhandle(fd: ::c_int) -> ::intptr_t;
+    pub fn setmode(fd: ::c_int, mode: ::c_int) -> ::c_int;
+}
 
 // sys/errno.h
 extern "C" {
@@ -3140,6 +3966,123 @@ extern "C" {
     ) -> ::c_int;
 }
 
+// spawn.h
+pub type posix_spawnattr_t = *mut ::c_void;
+pub type posix_spawn_file_actions_t = *mut ::c_void;
+
+pub const POSIX_SPAWN_RESETIDS: ::c_short = 0x01;
+pub const POSIX_SPAWN_SETPGROUP: ::c_short = 0x02;
+pub const POSIX_SPAWN_SETSCHEDPARAM: ::c_short = 0x04;
+pub const POSIX_SPAWN_SETSCHEDULER: ::c_short = 0x08;
+pub const POSIX_SPAWN_SETSIGDEF: ::c_short = 0x10;
+pub const POSIX_SPAWN_SETSIGMASK: ::c_short = 0x20;
+
+extern "C" {
+    pub fn posix_spawn(
+        pid: *mut ::pid_t,
+        path: *const c_char,
+        file_actions: *const posix_spawn_file_actions_t,
+        attrp: *const posix_spawnattr_t,
+        argv: *const *mut c_char,
+        envp: *const *mut c_char,
+    ) -> ::c_int;
+
+    pub fn posix_spawnp(
+        pid: *mut ::pid_t,
+        file: *const c_char,
+        file_actions: *const posix_spawn_file_actions_t,
+        attrp: *const posix_spawnattr_t,
+        argv: *const *mut c_char,
+        envp: *const *mut c_char,
+    ) -> ::c_int;
+
+    pub fn posix_spawn_file_actions_init(actions: *mut posix_spawn_file_actions_t) -> ::c_int;
+    pub fn posix_spawn_file_actions_destroy(actions: *mut posix_spawn_file_actions_t) -> ::c_int;
+
+    pub fn posix_spawn_file_actions_addopen(
+        actions: *mut posix_spawn_file_actions_t,
+        fd: ::c_int,
+        path: *const c_char,
+        oflag: ::c_int,
+        mode: mode_t,
+    ) -> ::c_int;
+
+    pub fn posix_spawn_file_actions_addclose(
+        actions: *mut posix_spawn_file_actions_t,
+        fd: ::c_int,
+    ) -> ::c_int;
+
+    pub fn posix_spawn_file_actions_adddup2(
+        actions: *mut posix_spawn_file_actions_t,
+        fd: ::c_int,
+        newfd: ::c_int,
+    ) -> ::c_int;
+
+    pub fn posix_spawn_file_actions_addchdir_np(
+        actions: *mut posix_spawn_file_actions_t,
+        path: *const c_char,
+    ) -> ::c_int;
+
+    pub fn posix_spawn_file_actions_addfchdir_np(
+        actions: *mut posix_spawn_file_actions_t,
+        fd: ::c_int,
+    ) -> ::c_int;
+
+    pub fn posix_spawnattr_init(attr: *mut posix_spawnattr_t) -> ::c_int;
+    pub fn posix_spawnattr_destroy(attr: *mut posix_spawnattr_t) -> ::c_int;
+
+    pub fn posix_spawnattr_getflags(
+        attr: *const posix_spawnattr_t,
+        flags: *mut ::c_short,
+    ) -> ::c_int;
+
+    pub fn posix_spawnattr_setflags(attr: *mut posix_spawnattr_t, flags: ::c_short) -> ::c_int;
+
+    pub fn posix_spawnattr_getpgroup(
+        attr: *const posix_spawnattr_t,
+        flags: *mut ::pid_t,
+    ) -> ::c_int;
+
+    pub fn posix_spawnattr_setpgroup(attr: *mut posix_spawnattr_t, flags: ::pid_t) -> ::c_int;
+
+    pub fn posix_spawnattr_getschedparam(
+        attr: *const posix_spawnattr_t,
+        param: *mut sched_param,
+    ) -> ::c_int;
+
+    pub fn posix_spawnattr_setschedparam(
+        attr: *mut posix_spawnattr_t,
+        param: *const sched_param,
+    ) -> ::c_int;
+
+    pub fn posix_spawnattr_getschedpolicy(
+        attr: *const posix_spawnattr_t,
+        flags: *mut ::c_int,
+    ) -> ::c_int;
+
+    pub fn posix_spawnattr_setschedpolicy(attr: *mut posix_spawnattr_t, flags: ::c_int) -> ::c_int;
+
+    pub fn posix_spawnattr_getsigdefault(
+        attr: *const posix_spawnattr_t,
+        default: *mut sigset_t,
+    ) -> ::c_int;
+
+    pub fn posix_spawnattr_setsigdefault(
+        attr: *mut posix_spawnattr_t,
+        default: *const sigset_t,
+    ) -> ::c_int;
+
+    pub fn posix_spawnattr_getsigmask(
+        attr: *const posix_spawnattr_t,
+        default: *mut sigset_t,
+    ) -> ::c_int;
+
+    pub fn posix_spawnattr_setsigmask(
+        attr: *mut posix_spawnattr_t,
+        default: *const sigset_t,
+    ) -> ::c_int;
+}
+
 // pthread.h
 pub const PTHREAD_COND_INITIALIZER: pthread_cond_t = 21 as *mut _;
 pub const PTHREAD_CREATE_DETACHED: ::c_int = 1;
@@ -3179,6 +4122,13 @@ extern "C" {
 
     pub fn pthread_cancel(thread: pthread_t) -> ::c_int;
 
+    pub fn pthread_cond_clockwait(
+        cond: *mut pthread_cond_t,
+        lock: *mut pthread_mutex_t,
+        clock_id: clockid_t,
+        abstime: *const ::timespec,
+    ) -> ::c_int;
+
     pub fn pthread_condattr_getclock(
         attr: *const pthread_condattr_t,
         clock_id: *mut clockid_t,
@@ -3237,6 +4187,11 @@ extern "C" {
         lock: *mut pthread_mutex_t,
         abstime: *const ::timespec,
     ) -> ::c_int;
+    pub fn pthread_mutex_clocklock(
+        lock: *mut pthread_mutex_t,
+        clock_id: clockid_t,
+        abstime: *const ::timespec,
+    ) -> ::c_int;
 
     pub fn pthread_mutexattr_getprotocol(
         attr: *const pthread_mutexattr_t,
@@ -3271,6 +4226,25 @@ extern "C" {
 
     pub fn pthread_rwlockattr_setpshared(attr: *mut pthread_rwlockattr_t, val: ::c_int) -> ::c_int;
 
+    pub fn pthread_rwlock_timedrdlock(
+        rwlock: *mut pthread_rwlock_t,
+        abstime: *const ::timespec,
+    ) -> ::c_int;
+    pub fn pthread_rwlock_timedwrlock(
+        rwlock: *mut pthread_rwlock_t,
+        abstime: *const ::timespec,
+    ) -> ::c_int;
+    pub fn pthread_rwlock_clockrdlock(
+        rwlock: *mut pthread_rwlock_t,
+        clock_id: clockid_t,
+        abstime: *const ::timespec,
+    ) -> ::c_int;
+    pub fn pthread_rwlock_clockwrlock(
+        rwlock: *mut pthread_rwlock_t,
+        clock_id: clockid_t,
+        abstime: *const ::timespec,
+    ) -> ::c_int;
+
     pub fn pthread_setschedparam(
         native: pthread_t,
         policy: ::c_int,
@@ -3296,6 +4270,15 @@ extern "C" {
 
     pub fn pthread_setname_np(thread: pthread_t, name: *const c_char) -> ::c_int;
     pub fn pthread_sigqueue(thread: *mut pthread_t, sig: ::c_int, value: ::sigval) -> ::c_int;
+
+    pub fn pthread_tryjoin_np(thread: pthread_t, retval: *mut *mut ::c_void) -> ::c_int;
+    pub fn pthread_timedjoin_np(
+        thread: pthread_t,
+        retval: *mut *mut ::c_void,
+        abstime: *const ::timespec,
+    ) -> ::c_int;
+
+    pub fn pthread_yield() -> ::c_int;
 }
 
 // bits/endian.h
@@ -3359,6 +4342,173 @@ extern "C" {
     pub fn cygwin_umount(target: *const c_char, flags: ::c_uint) -> ::c_int;
 }
 
+// mntent.h
+s! {
+    pub struct mntent {
+        pub mnt_fsname: *mut c_char,
+        pub mnt_dir: *mut c_char,
+        pub mnt_type: *mut c_char,
+        pub mnt_opts: *mut c_char,
+        pub mnt_freq: ::c_int,
+        pub mnt_passno: ::c_int,
+    }
+}
+
+extern "C" {
+    pub fn setmntent(filename: *const c_char, ty: *const c_char) -> *mut ::FILE;
+    pub fn getmntent(stream: *mut ::FILE) -> *mut mntent;
+    pub fn getmntent_r(
+        stream: *mut ::FILE,
+        mntbuf: *mut mntent,
+        buf: *mut c_char,
+        buflen: ::c_int,
+    ) -> *mut mntent;
+    pub fn endmntent(streamp: *mut ::FILE) -> ::c_int;
+}
+
+// sys/cygwin.h
+pub type cygwin_conv_path_t = ::c_uint;
+
+pub const CCP_POSIX_TO_WIN_A: cygwin_conv_path_t = 0;
+pub const CCP_POSIX_TO_WIN_W: cygwin_conv_path_t = 1;
+pub const CCP_WIN_A_TO_POSIX: cygwin_conv_path_t = 2;
+pub const CCP_WIN_W_TO_POSIX: cygwin_conv_path_t = 3;
+pub const CCP_CONVTYPE_MASK: cygwin_conv_path_t = 3;
+pub const CCP_ABSOLUTE: cygwin_conv_path_t = 0;
+pub const CCP_RELATIVE: cygwin_conv_path_t = 0x100;
+pub const CCP_PROC_CYGDRIVE: cygwin_conv_path_t = 0x200;
+pub const CCP_CONVFLAGS_MASK: cygwin_conv_path_t = 0x300;
+
+extern "C" {
+    pub fn cygwin_conv_path(
+        what: cygwin_conv_path_t,
+        from: *const ::c_void,
+        to: *mut ::c_void,
+        size: ::size_t,
+    ) -> ::ssize_t;
+
+    pub fn cygwin_conv_path_list(
+        what: cygwin_conv_path_t,
+        from: *const ::c_void,
+        to: *mut ::c_void,
+        size: ::size_t,
+    ) -> ::ssize_t;
+
+    pub fn cygwin_create_path(what: cygwin_conv_path_t, from: *const ::c_void) -> *mut ::c_void;
+}
+
+pub type cygwin_getinfo_types = ::c_uint;
+
+pub const CW_LOCK_PINFO: cygwin_getinfo_types = 0;
+pub const CW_UNLOCK_PINFO: cygwin_getinfo_types = 1;
+pub const CW_GETTHREADNAME: cygwin_getinfo_types = 2;
+pub const CW_GETPINFO: cygwin_getinfo_types = 3;
+pub const CW_SETPINFO: cygwin_getinfo_types = 4;
+pub const CW_SETTHREADNAME: cygwin_getinfo_types = 5;
+pub const CW_GETVERSIONINFO: cygwin_getinfo_types = 6;
+pub const CW_READ_V1_MOUNT_TABLES: cygwin_getinfo_types = 7;
+pub const CW_USER_DATA: cygwin_getinfo_types = 8;
+pub const CW_PERFILE: cygwin_getinfo_types = 9;
+pub const CW_GET_CYGDRIVE_PREFIXES: cygwin_getinfo_types = 10;
+pub const CW_GETPINFO_FULL: cygwin_getinfo_types = 11;
+pub const CW_INIT_EXCEPTIONS: cygwin_getinfo_types = 12;
+pub const CW_GET_CYGDRIVE_INFO: cygwin_getinfo_types = 13;
+pub const CW_SET_CYGWIN_REGISTRY_NAME: cygwin_getinfo_types = 14;
+pub const CW_GET_CYGWIN_REGISTRY_NAME: cygwin_getinfo_types = 15;
+pub const CW_STRACE_TOGGLE: cygwin_getinfo_types = 16;
+pub const CW_STRACE_ACTIVE: cygwin_getinfo_types = 17;
+pub const CW_CYGWIN_PID_TO_WINPID: cygwin_getinfo_types = 18;
+pub const CW_EXTRACT_DOMAIN_AND_USER: cygwin_getinfo_types = 19;
+pub const CW_CMDLINE: cygwin_getinfo_types = 20;
+pub const CW_CHECK_NTSEC: cygwin_getinfo_types = 21;
+pub const CW_GET_ERRNO_FROM_WINERROR: cygwin_getinfo_types = 22;
+pub const CW_GET_POSIX_SECURITY_ATTRIBUTE: cygwin_getinfo_types = 23;
+pub const CW_GET_SHMLBA: cygwin_getinfo_types = 24;
+pub const CW_GET_UID_FROM_SID: cygwin_getinfo_types = 25;
+pub const CW_GET_GID_FROM_SID: cygwin_getinfo_types = 26;
+pub const CW_GET_BINMODE: cygwin_getinfo_types = 27;
+pub const CW_HOOK: cygwin_getinfo_types = 28;
+pub const CW_ARGV: cygwin_getinfo_types = 29;
+pub const CW_ENVP: cygwin_getinfo_types = 30;
+pub const CW_DEBUG_SELF: cygwin_getinfo_types = 31;
+pub const CW_SYNC_WINENV: cygwin_getinfo_types = 32;
+pub const CW_CYGTLS_PADSIZE: cygwin_getinfo_types = 33;
+pub const CW_SET_DOS_FILE_WARNING: cygwin_getinfo_types = 34;
+pub const CW_SET_PRIV_KEY: cygwin_getinfo_types = 35;
+pub const CW_SETERRNO: cygwin_getinfo_types = 36;
+pub const CW_EXIT_PROCESS: cygwin_getinfo_types = 37;
+pub const CW_SET_EXTERNAL_TOKEN: cygwin_getinfo_types = 38;
+pub const CW_GET_INSTKEY: cygwin_getinfo_types = 39;
+pub const CW_INT_SETLOCALE: cygwin_getinfo_types = 40;
+pub const CW_CVT_MNT_OPTS: cygwin_getinfo_types = 41;
+pub const CW_LST_MNT_OPTS: cygwin_getinfo_types = 42;
+pub const CW_STRERROR: cygwin_getinfo_types = 43;
+pub const CW_CVT_ENV_TO_WINENV: cygwin_getinfo_types = 44;
+pub const CW_ALLOC_DRIVE_MAP: cygwin_getinfo_types = 45;
+pub const CW_MAP_DRIVE_MAP: cygwin_getinfo_types = 46;
+pub const CW_FREE_DRIVE_MAP: cygwin_getinfo_types = 47;
+pub const CW_SETENT: cygwin_getinfo_types = 48;
+pub const CW_GETENT: cygwin_getinfo_types = 49;
+pub const CW_ENDENT: cygwin_getinfo_types = 50;
+pub const CW_GETNSSSEP: cygwin_getinfo_types = 51;
+pub const CW_GETPWSID: cygwin_getinfo_types = 52;
+pub const CW_GETGRSID: cygwin_getinfo_types = 53;
+pub const CW_CYGNAME_FROM_WINNAME: cygwin_getinfo_types = 54;
+pub const CW_FIXED_ATEXIT: cygwin_getinfo_types = 55;
+pub const CW_GETNSS_PWD_SRC: cygwin_getinfo_types = 56;
+pub const CW_GETNSS_GRP_SRC: cygwin_getinfo_types = 57;
+pub const CW_EXCEPTION_RECORD_FROM_SIGINFO_T: cygwin_getinfo_types = 58;
+pub const CW_CYGHEAP_PROFTHR_ALL: cygwin_getinfo_types = 59;
+pub const CW_WINPID_TO_CYGWIN_PID: cygwin_getinfo_types = 60;
+pub const CW_MAX_CYGWIN_PID: cygwin_getinfo_types = 61;
+
+pub const EXTERNAL_PINFO_VERSION_16_BIT: u32 = 0;
+pub const EXTERNAL_PINFO_VERSION_32_BIT: u32 = 1;
+pub const EXTERNAL_PINFO_VERSION_32_LP: u32 = 2;
+pub const EXTERNAL_PINFO_VERSION: u32 = EXTERNAL_PINFO_VERSION_32_LP;
+
+// intentionally not public, only used for the legacy ids in external_pinfo
+type __uid16_t = u16;
+type __gid16_t = u16;
+
+s! {
+    pub struct external_pinfo {
+        pub pid: ::pid_t,
+        pub ppid: ::pid_t,
+        pub exitcode: u32,
+        pub dwProcessId: u32,
+        pub dwSpawnedProcessId: u32,
+        pub uid: __uid16_t,
+        pub gid: __gid16_t,
+        pub pgid: ::pid_t,
+        pub sid: ::pid_t,
+        pub ctty: ::c_int,
+        pub umask: mode_t,
+        pub start_time: c_long,
+        pub rusage_self: ::rusage,
+        pub rusage_children: ::rusage,
+        pub progname: [c_char; 260],
+        pub strace_mask: u32,
+        pub version: u32,
+        pub process_state: u32,
+        pub uid32: ::uid_t,
+        pub gid32: ::gid_t,
+        pub progname_long: *mut c_char,
+    }
+}
+
+extern "C" {
+    pub fn cygwin_internal(what: cygwin_getinfo_types, ...) -> ::uintptr_t;
+    pub fn cygwin_winpid_to_pid(winpid: ::c_int) -> ::pid_t;
+    pub fn cygwin_attach_handle_to_fd(
+        name: *mut c_char,
+        fd: ::c_int,
+        handle: *mut ::c_void,
+        bin: ::mode_t,
+        access: u32,
+    ) -> ::c_int;
+}
+
 // sys/features.h
 pub const _POSIX_VDISABLE: ::cc_t = 0;
 
@@ -3369,6 +4519,167 @@ extern "C" {
     pub fn telldir(dirp: *mut ::DIR) -> ::c_long;
 }
 
+// fnmatch.h
+pub const FNM_NOMATCH: ::c_int = 1;
+
+pub const FNM_NOESCAPE: ::c_int = 0x01;
+pub const FNM_PATHNAME: ::c_int = 0x02;
+pub const FNM_PERIOD: ::c_int = 0x04;
+pub const FNM_LEADING_DIR: ::c_int = 0x08;
+pub const FNM_CASEFOLD: ::c_int = 0x10;
+pub const FNM_IGNORECASE: ::c_int = FNM_CASEFOLD;
+pub const FNM_FILE_NAME: ::c_int = FNM_PATHNAME;
+
+extern "C" {
+    pub fn fnmatch(pattern: *const ::c_char, string: *const ::c_char, flags: ::c_int) -> ::c_int;
+}
+
+// glob.h
+s! {
+    pub struct glob_t {
+        pub gl_pathc: ::size_t,
+        pub gl_matchc: ::size_t,
+        pub gl_offs: ::size_t,
+        pub gl_flags: ::c_int,
+        pub gl_pathv: *mut *mut ::c_char,
+        __unused3: *mut ::c_void,
+        __unused4: *mut ::c_void,
+        __unused5: *mut ::c_void,
+        __unused6: *mut ::c_void,
+        __unused7: *mut ::c_void,
+        __unused8: *mut ::c_void,
+    }
+}
+
+pub const GLOB_APPEND: ::c_int = 0x0001;
+pub const GLOB_DOOFFS: ::c_int = 0x0002;
+pub const GLOB_ERR: ::c_int = 0x0004;
+pub const GLOB_MARK: ::c_int = 0x0008;
+pub const GLOB_NOCHECK: ::c_int = 0x0010;
+pub const GLOB_NOSORT: ::c_int = 0x0020;
+pub const GLOB_ALTDIRFUNC: ::c_int = 0x0040;
+pub const GLOB_BRACE: ::c_int = 0x0080;
+pub const GLOB_MAGCHAR: ::c_int = 0x0100;
+pub const GLOB_NOMAGIC: ::c_int = 0x0200;
+pub const GLOB_QUOTE: ::c_int = 0x0400;
+pub const GLOB_TILDE: ::c_int = 0x0800;
+pub const GLOB_LIMIT: ::c_int = 0x1000;
+pub const GLOB_NOESCAPE: ::c_int = 0x2000;
+
+pub const GLOB_NOSPACE: ::c_int = -1;
+pub const GLOB_ABORTED: ::c_int = -2;
+pub const GLOB_NOMATCH: ::c_int = -3;
+pub const GLOB_NOSYS: ::c_int = -4;
+
+extern "C" {
+    pub fn glob(
+        pattern: *const ::c_char,
+        flags: ::c_int,
+        errfunc: ::Option<extern "C" fn(epath: *const ::c_char, errno: ::c_int) -> ::c_int>,
+        pglob: *mut glob_t,
+    ) -> ::c_int;
+    pub fn globfree(pglob: *mut glob_t);
+}
+
+// regex.h
+pub type regoff_t = off_t;
+
+s! {
+    pub struct regex_t {
+        __re_magic: ::c_int,
+        __re_nsub: ::size_t,
+        __re_endp: *const ::c_char,
+        __re_g: *mut ::c_void,
+    }
+
+    pub struct regmatch_t {
+        pub rm_so: regoff_t,
+        pub rm_eo: regoff_t,
+    }
+}
+
+pub const REG_BASIC: ::c_int = 0o0000;
+pub const REG_EXTENDED: ::c_int = 0o0001;
+pub const REG_ICASE: ::c_int = 0o0002;
+pub const REG_NOSUB: ::c_int = 0o0004;
+pub const REG_NEWLINE: ::c_int = 0o0010;
+pub const REG_NOSPEC: ::c_int = 0o0020;
+pub const REG_PEND: ::c_int = 0o0040;
+pub const REG_DUMP: ::c_int = 0o0200;
+
+pub const REG_NOMATCH: ::c_int = 1;
+pub const REG_BADPAT: ::c_int = 2;
+pub const REG_ECOLLATE: ::c_int = 3;
+pub const REG_ECTYPE: ::c_int = 4;
+pub const REG_EESCAPE: ::c_int = 5;
+pub const REG_ESUBREG: ::c_int = 6;
+pub const REG_EBRACK: ::c_int = 7;
+pub const REG_EPAREN: ::c_int = 8;
+pub const REG_EBRACE: ::c_int = 9;
+pub const REG_BADBR: ::c_int = 10;
+pub const REG_ERANGE: ::c_int = 11;
+pub const REG_ESPACE: ::c_int = 12;
+pub const REG_BADRPT: ::c_int = 13;
+pub const REG_EMPTY: ::c_int = 14;
+pub const REG_ASSERT: ::c_int = 15;
+pub const REG_INVARG: ::c_int = 16;
+pub const REG_ATOI: ::c_int = 255;
+pub const REG_ITOA: ::c_int = 0o0400;
+
+pub const REG_NOTBOL: ::c_int = 0o00001;
+pub const REG_NOTEOL: ::c_int = 0o00002;
+pub const REG_STARTEND: ::c_int = 0o00004;
+pub const REG_TRACE: ::c_int = 0o00400;
+pub const REG_LARGE: ::c_int = 0o01000;
+pub const REG_BACKR: ::c_int = 0o02000;
+
+extern "C" {
+    pub fn regcomp(preg: *mut regex_t, pattern: *const ::c_char, cflags: ::c_int) -> ::c_int;
+    pub fn regexec(
+        preg: *const regex_t,
+        input: *const ::c_char,
+        nmatch: ::size_t,
+        pmatch: *mut regmatch_t,
+        eflags: ::c_int,
+    ) -> ::c_int;
+    pub fn regerror(
+        errcode: ::c_int,
+        preg: *const regex_t,
+        errbuf: *mut ::c_char,
+        errbuf_size: ::size_t,
+    ) -> ::size_t;
+    pub fn regfree(preg: *mut regex_t);
+}
+
+// wordexp.h
+s! {
+    pub struct wordexp_t {
+        pub we_wordc: ::size_t,
+        pub we_wordv: *mut *mut ::c_char,
+        pub we_offs: ::size_t,
+    }
+}
+
+pub const WRDE_DOOFFS: ::c_int = 0x0001;
+pub const WRDE_APPEND: ::c_int = 0x0002;
+pub const WRDE_NOCMD: ::c_int = 0x0004;
+pub const WRDE_REUSE: ::c_int = 0x0008;
+pub const WRDE_SHOWERR: ::c_int = 0x0010;
+pub const WRDE_UNDEF: ::c_int = 0x0020;
+
+pub const WRDE_SUCCESS: ::c_int = 0;
+pub const WRDE_NOSPACE: ::c_int = 1;
+pub const WRDE_BADCHAR: ::c_int = 2;
+pub const WRDE_BADVAL: ::c_int = 3;
+pub const WRDE_CMDSUB: ::c_int = 4;
+pub const WRDE_SYNTAX: ::c_int = 5;
+pub const WRDE_NOSYS: ::c_int = 6;
+
+extern "C" {
+    pub fn wordexp(words: *const ::c_char, pwordexp: *mut wordexp_t, flags: ::c_int) -> ::c_int;
+    pub fn wordfree(pwordexp: *mut wordexp_t);
+}
+
 // machine/types.h
 pub type vm_offset_t = c_ulong;
 
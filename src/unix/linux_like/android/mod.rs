@@ -27,6 +27,16 @@ pub type loff_t = ::c_longlong;
 pub type __kernel_loff_t = ::c_longlong;
 pub type __kernel_pid_t = ::c_int;
 
+cfg_if! {
+    if #[cfg(doc)] {
+        // Used to type the `_IOC` family of request helpers.
+        pub(crate) type Ioctl = ::c_int;
+    } else {
+        #[doc(hidden)]
+        pub type Ioctl = ::c_int;
+    }
+}
+
 pub type __u8 = ::c_uchar;
 pub type __u16 = ::c_ushort;
 pub type __s16 = ::c_short;
@@ -1617,6 +1627,36 @@ cfg_if! {
     }
 }
 
+// asm/ioctl.h
+cfg_if! {
+    if #[cfg(any(target_arch = "mips",
+                 target_arch = "mips64",
+                 target_arch = "powerpc",
+                 target_arch = "powerpc64"))] {
+        pub const _IOC_SIZEBITS: ::c_uint = 13;
+        pub const _IOC_DIRBITS: ::c_uint = 3;
+        pub const _IOC_NONE: ::c_uint = 1;
+        pub const _IOC_READ: ::c_uint = 2;
+        pub const _IOC_WRITE: ::c_uint = 4;
+    } else {
+        pub const _IOC_SIZEBITS: ::c_uint = 14;
+        pub const _IOC_DIRBITS: ::c_uint = 2;
+        pub const _IOC_NONE: ::c_uint = 0;
+        pub const _IOC_WRITE: ::c_uint = 1;
+        pub const _IOC_READ: ::c_uint = 2;
+    }
+}
+pub const _IOC_NRBITS: ::c_uint = 8;
+pub const _IOC_TYPEBITS: ::c_uint = 8;
+pub const _IOC_NRMASK: ::c_uint = (1 << _IOC_NRBITS) - 1;
+pub const _IOC_TYPEMASK: ::c_uint = (1 << _IOC_TYPEBITS) - 1;
+pub const _IOC_SIZEMASK: ::c_uint = (1 << _IOC_SIZEBITS) - 1;
+pub const _IOC_DIRMASK: ::c_uint = (1 << _IOC_DIRBITS) - 1;
+pub const _IOC_NRSHIFT: ::c_uint = 0;
+pub const _IOC_TYPESHIFT: ::c_uint = _IOC_NRSHIFT + _IOC_NRBITS;
+pub const _IOC_SIZESHIFT: ::c_uint = _IOC_TYPESHIFT + _IOC_TYPEBITS;
+pub const _IOC_DIRSHIFT: ::c_uint = _IOC_SIZESHIFT + _IOC_SIZEBITS;
+
 pub const ST_RDONLY: ::c_ulong = 1;
 pub const ST_NOSUID: ::c_ulong = 2;
 pub const ST_NODEV: ::c_ulong = 4;
@@ -3246,6 +3286,44 @@ safe_f! {
         ((ma & 0xfff) << 8) | (mi & 0xff) | ((mi & 0xfff00) << 12)
     }
 
+    pub {const} fn _IOC(dir: ::c_uint, ty: ::c_uint, nr: ::c_uint, size: ::size_t) -> ::Ioctl {
+        ((dir << _IOC_DIRSHIFT)
+            | (ty << _IOC_TYPESHIFT)
+            | (nr << _IOC_NRSHIFT)
+            | ((size as ::c_uint) << _IOC_SIZESHIFT)) as ::Ioctl
+    }
+
+    pub {const} fn _IO(ty: ::c_uint, nr: ::c_uint) -> ::Ioctl {
+        _IOC(_IOC_NONE, ty, nr, 0)
+    }
+
+    pub {const} fn _IOR(ty: ::c_uint, nr: ::c_uint, size: ::size_t) -> ::Ioctl {
+        _IOC(_IOC_READ, ty, nr, size)
+    }
+
+    pub {const} fn _IOW(ty: ::c_uint, nr: ::c_uint, size: ::size_t) -> ::Ioctl {
+        _IOC(_IOC_WRITE, ty, nr, size)
+    }
+
+    pub {const} fn _IOWR(ty: ::c_uint, nr: ::c_uint, size: ::size_t) -> ::Ioctl {
+        _IOC(_IOC_READ | _IOC_WRITE, ty, nr, size)
+    }
+
+    pub {const} fn _IOC_DIR(nr: ::Ioctl) -> ::c_uint {
+        ((nr as ::c_uint) >> _IOC_DIRSHIFT) & _IOC_DIRMASK
+    }
+
+    pub {const} fn _IOC_TYPE(nr: ::Ioctl) -> ::c_uint {
+        ((nr as ::c_uint) >> _IOC_TYPESHIFT) & _IOC_TYPEMASK
+    }
+
+    pub {const} fn _IOC_NR(nr: ::Ioctl) -> ::c_uint {
+        ((nr as ::c_uint) >> _IOC_NRSHIFT) & _IOC_NRMASK
+    }
+
+    pub {const} fn _IOC_SIZE(nr: ::Ioctl) -> ::c_uint {
+        ((nr as ::c_uint) >> _IOC_SIZESHIFT) & _IOC_SIZEMASK
+    }
 }
 
 extern "C" {
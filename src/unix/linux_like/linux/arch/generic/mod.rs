@@ -128,6 +128,15 @@ cfg_if! {
 pub const SCM_TIMESTAMPNS: ::c_int = SO_TIMESTAMPNS;
 pub const SCM_TIMESTAMPING: ::c_int = SO_TIMESTAMPING;
 
+// include/uapi/asm-generic/ioctl.h
+pub const _IOC_NRBITS: ::c_uint = 8;
+pub const _IOC_TYPEBITS: ::c_uint = 8;
+pub const _IOC_SIZEBITS: ::c_uint = 14;
+pub const _IOC_DIRBITS: ::c_uint = 2;
+pub const _IOC_NONE: ::c_uint = 0;
+pub const _IOC_WRITE: ::c_uint = 1;
+pub const _IOC_READ: ::c_uint = 2;
+
 // Ioctl Constants
 
 pub const TCGETS: ::Ioctl = 0x5401;
@@ -93,6 +93,15 @@ pub const FICLONERANGE: ::c_ulong = 0x8020940D;
 pub const SCM_TIMESTAMPNS: ::c_int = SO_TIMESTAMPNS;
 pub const SCM_TIMESTAMPING: ::c_int = SO_TIMESTAMPING;
 
+// arch/powerpc/include/uapi/asm/ioctl.h
+pub const _IOC_NRBITS: ::c_uint = 8;
+pub const _IOC_TYPEBITS: ::c_uint = 8;
+pub const _IOC_SIZEBITS: ::c_uint = 13;
+pub const _IOC_DIRBITS: ::c_uint = 3;
+pub const _IOC_NONE: ::c_uint = 1;
+pub const _IOC_READ: ::c_uint = 2;
+pub const _IOC_WRITE: ::c_uint = 4;
+
 // Ioctl Constants
 
 cfg_if! {
@@ -865,6 +865,14 @@ pub const SYS_memfd_secret: ::c_long = 447;
 pub const SYS_process_mrelease: ::c_long = 448;
 pub const SYS_futex_waitv: ::c_long = 449;
 pub const SYS_set_mempolicy_home_node: ::c_long = 450;
+pub const SYS_cachestat: ::c_long = 451;
+pub const SYS_fchmodat2: ::c_long = 452;
+pub const SYS_map_shadow_stack: ::c_long = 453;
+pub const SYS_futex_wake: ::c_long = 454;
+pub const SYS_futex_wait: ::c_long = 455;
+pub const SYS_futex_requeue: ::c_long = 456;
+pub const SYS_statmount: ::c_long = 457;
+pub const SYS_listmount: ::c_long = 458;
 
 cfg_if! {
     if #[cfg(libc_align)] {
@@ -597,6 +597,14 @@ pub const SYS_memfd_secret: ::c_long = 5000 + 447;
 pub const SYS_process_mrelease: ::c_long = 5000 + 448;
 pub const SYS_futex_waitv: ::c_long = 5000 + 449;
 pub const SYS_set_mempolicy_home_node: ::c_long = 5000 + 450;
+pub const SYS_cachestat: ::c_long = 5000 + 451;
+pub const SYS_fchmodat2: ::c_long = 5000 + 452;
+pub const SYS_map_shadow_stack: ::c_long = 5000 + 453;
+pub const SYS_futex_wake: ::c_long = 5000 + 454;
+pub const SYS_futex_wait: ::c_long = 5000 + 455;
+pub const SYS_futex_requeue: ::c_long = 5000 + 456;
+pub const SYS_statmount: ::c_long = 5000 + 457;
+pub const SYS_listmount: ::c_long = 5000 + 458;
 
 pub const SFD_CLOEXEC: ::c_int = 0x080000;
 
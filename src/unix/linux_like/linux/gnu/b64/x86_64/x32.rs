@@ -366,6 +366,14 @@ pub const SYS_memfd_secret: ::c_long = __X32_SYSCALL_BIT + 447;
 pub const SYS_process_mrelease: ::c_long = __X32_SYSCALL_BIT + 448;
 pub const SYS_futex_waitv: ::c_long = __X32_SYSCALL_BIT + 449;
 pub const SYS_set_mempolicy_home_node: ::c_long = __X32_SYSCALL_BIT + 450;
+pub const SYS_cachestat: ::c_long = __X32_SYSCALL_BIT + 451;
+pub const SYS_fchmodat2: ::c_long = __X32_SYSCALL_BIT + 452;
+pub const SYS_map_shadow_stack: ::c_long = __X32_SYSCALL_BIT + 453;
+pub const SYS_futex_wake: ::c_long = __X32_SYSCALL_BIT + 454;
+pub const SYS_futex_wait: ::c_long = __X32_SYSCALL_BIT + 455;
+pub const SYS_futex_requeue: ::c_long = __X32_SYSCALL_BIT + 456;
+pub const SYS_statmount: ::c_long = __X32_SYSCALL_BIT + 457;
+pub const SYS_listmount: ::c_long = __X32_SYSCALL_BIT + 458;
 pub const SYS_rt_sigaction: ::c_long = __X32_SYSCALL_BIT + 512;
 pub const SYS_rt_sigreturn: ::c_long = __X32_SYSCALL_BIT + 513;
 pub const SYS_ioctl: ::c_long = __X32_SYSCALL_BIT + 514;
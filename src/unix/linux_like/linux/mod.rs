@@ -685,6 +685,203 @@ s! {
         pub rlim_cur: rlim64_t,
         pub rlim_max: rlim64_t,
     }
+
+    // linux/io_uring.h
+    pub struct __c_anonymous_io_uring_sqe_cmd_op {
+        pub cmd_op: ::__u32,
+        pub __pad1: ::__u32,
+    }
+
+    pub struct __c_anonymous_io_uring_sqe_addr_len {
+        pub addr_len: ::__u16,
+        pub __pad3: [::__u16; 1],
+    }
+
+    pub struct __c_anonymous_io_uring_sqe_addr3 {
+        pub addr3: ::__u64,
+        pub __pad2: [::__u64; 1],
+    }
+
+    pub struct io_uring_cqe {
+        pub user_data: ::__u64,
+        pub res: ::__s32,
+        pub flags: ::__u32,
+        pub big_cqe: [::__u64; 0],
+    }
+
+    pub struct io_sqring_offsets {
+        pub head: ::__u32,
+        pub tail: ::__u32,
+        pub ring_mask: ::__u32,
+        pub ring_entries: ::__u32,
+        pub flags: ::__u32,
+        pub dropped: ::__u32,
+        pub array: ::__u32,
+        pub resv1: ::__u32,
+        pub resv2: ::__u64,
+    }
+
+    pub struct io_cqring_offsets {
+        pub head: ::__u32,
+        pub tail: ::__u32,
+        pub ring_mask: ::__u32,
+        pub ring_entries: ::__u32,
+        pub overflow: ::__u32,
+        pub cqes: ::__u32,
+        pub flags: ::__u32,
+        pub resv1: ::__u32,
+        pub resv2: ::__u64,
+    }
+
+    pub struct io_uring_params {
+        pub sq_entries: ::__u32,
+        pub cq_entries: ::__u32,
+        pub flags: ::__u32,
+        pub sq_thread_cpu: ::__u32,
+        pub sq_thread_idle: ::__u32,
+        pub features: ::__u32,
+        pub wq_fd: ::__u32,
+        pub resv: [::__u32; 3],
+        pub sq_off: io_sqring_offsets,
+        pub cq_off: io_cqring_offsets,
+    }
+
+    pub struct io_uring_probe_op {
+        pub op: ::__u8,
+        pub resv: ::__u8,
+        pub flags: ::__u16,
+        pub resv2: ::__u32,
+    }
+
+    pub struct io_uring_probe {
+        pub last_op: ::__u8,
+        pub ops_len: ::__u8,
+        pub resv: ::__u16,
+        pub resv2: [::__u32; 3],
+        pub ops: [io_uring_probe_op; 0],
+    }
+
+    pub struct io_uring_buf {
+        pub addr: ::__u64,
+        pub len: ::__u32,
+        pub bid: ::__u16,
+        pub resv: ::__u16,
+    }
+
+    // The ring tail overlays the `resv` field of the first `io_uring_buf`,
+    // the buffers themselves start at the beginning of the ring.
+    pub struct io_uring_buf_ring {
+        pub resv1: ::__u64,
+        pub resv2: ::__u32,
+        pub resv3: ::__u16,
+        pub tail: ::__u16,
+    }
+
+    pub struct io_uring_buf_reg {
+        pub ring_addr: ::__u64,
+        pub ring_entries: ::__u32,
+        pub bgid: ::__u16,
+        pub pad: ::__u16,
+        pub resv: [::__u64; 3],
+    }
+
+    // linux/landlock.h
+    pub struct landlock_ruleset_attr {
+        pub handled_access_fs: ::__u64,
+        pub handled_access_net: ::__u64,
+        pub scoped: ::__u64,
+    }
+
+    pub struct landlock_net_port_attr {
+        pub allowed_access: ::__u64,
+        pub port: ::__u64,
+    }
+
+    // linux/mount.h
+    pub struct mount_attr {
+        pub attr_set: ::__u64,
+        pub attr_clr: ::__u64,
+        pub propagation: ::__u64,
+        pub userns_fd: ::__u64,
+    }
+
+    pub struct mnt_id_req {
+        pub size: ::__u32,
+        pub spare: ::__u32,
+        pub mnt_id: ::__u64,
+        pub param: ::__u64,
+        pub mnt_ns_id: ::__u64,
+    }
+
+    // linux/userfaultfd.h
+    pub struct __c_anonymous_uffd_msg_fork {
+        pub ufd: ::__u32,
+    }
+
+    pub struct __c_anonymous_uffd_msg_remap {
+        pub from: ::__u64,
+        pub to: ::__u64,
+        pub len: ::__u64,
+    }
+
+    pub struct __c_anonymous_uffd_msg_remove {
+        pub start: ::__u64,
+        pub end: ::__u64,
+    }
+
+    pub struct __c_anonymous_uffd_msg_reserved {
+        pub reserved1: ::__u64,
+        pub reserved2: ::__u64,
+        pub reserved3: ::__u64,
+    }
+
+    pub struct uffdio_api {
+        pub api: ::__u64,
+        pub features: ::__u64,
+        pub ioctls: ::__u64,
+    }
+
+    pub struct uffdio_range {
+        pub start: ::__u64,
+        pub len: ::__u64,
+    }
+
+    pub struct uffdio_register {
+        pub range: uffdio_range,
+        pub mode: ::__u64,
+        pub ioctls: ::__u64,
+    }
+
+    pub struct uffdio_copy {
+        pub dst: ::__u64,
+        pub src: ::__u64,
+        pub len: ::__u64,
+        pub mode: ::__u64,
+        pub copy: ::__s64,
+    }
+
+    pub struct uffdio_zeropage {
+        pub range: uffdio_range,
+        pub mode: ::__u64,
+        pub zeropage: ::__s64,
+    }
+
+    pub struct uffdio_writeprotect {
+        pub range: uffdio_range,
+        pub mode: ::__u64,
+    }
+
+    pub struct uffdio_continue {
+        pub range: uffdio_range,
+        pub mode: ::__u64,
+        pub mapped: ::__s64,
+    }
+
+    pub struct uffdio_poison {
+        pub range: uffdio_range,
+        pub mode: ::__u64,
+        pub updated: ::__s64,
+    }
 }
 
 s_no_extra_traits! {
@@ -804,6 +1001,50 @@ s_no_extra_traits! {
         pub d_type: ::c_uchar,
         pub d_name: [::c_char; 256],
     }
+
+    // linux/landlock.h
+    #[repr(packed)]
+    pub struct landlock_path_beneath_attr {
+        pub allowed_access: ::__u64,
+        pub parent_fd: ::__s32,
+    }
+
+    // linux/mount.h
+    pub struct statmount {
+        pub size: ::__u32,
+        pub mnt_opts: ::__u32,
+        pub mask: ::__u64,
+        pub sb_dev_major: ::__u32,
+        pub sb_dev_minor: ::__u32,
+        pub sb_magic: ::__u64,
+        pub sb_flags: ::__u32,
+        pub fs_type: ::__u32,
+        pub mnt_id: ::__u64,
+        pub mnt_parent_id: ::__u64,
+        pub mnt_id_old: ::__u32,
+        pub mnt_parent_id_old: ::__u32,
+        pub mnt_attr: ::__u64,
+        pub mnt_propagation: ::__u64,
+        pub mnt_peer_group: ::__u64,
+        pub mnt_master: ::__u64,
+        pub propagate_from: ::__u64,
+        pub mnt_root: ::__u32,
+        pub mnt_point: ::__u32,
+        pub mnt_ns_id: ::__u64,
+        pub fs_subtype: ::__u32,
+        pub sb_source: ::__u32,
+        pub opt_num: ::__u32,
+        pub opt_array: ::__u32,
+        pub opt_sec_num: ::__u32,
+        pub opt_sec_array: ::__u32,
+        pub supported_mask: ::__u64,
+        pub mnt_uidmap_num: ::__u32,
+        pub mnt_uidmap: ::__u32,
+        pub mnt_gidmap_num: ::__u32,
+        pub mnt_gidmap: ::__u32,
+        __spare2: [::__u64; 43],
+        pub str: [::c_char; 0],
+    }
 }
 
 s_no_extra_traits! {
@@ -835,6 +1076,123 @@ cfg_if! {
     }
 }
 
+cfg_if! {
+    if #[cfg(libc_union)] {
+        s_no_extra_traits! {
+            // linux/io_uring.h
+            #[allow(missing_debug_implementations)]
+            pub union __c_anonymous_io_uring_sqe_off {
+                pub off: ::__u64,
+                pub addr2: ::__u64,
+                pub cmd_op: __c_anonymous_io_uring_sqe_cmd_op,
+            }
+
+            #[allow(missing_debug_implementations)]
+            pub union __c_anonymous_io_uring_sqe_addr {
+                pub addr: ::__u64,
+                pub splice_off_in: ::__u64,
+            }
+
+            #[allow(missing_debug_implementations)]
+            pub union __c_anonymous_io_uring_sqe_rw_flags {
+                pub rw_flags: ::c_int,
+                pub fsync_flags: ::__u32,
+                pub poll_events: ::__u16,
+                pub poll32_events: ::__u32,
+                pub sync_range_flags: ::__u32,
+                pub msg_flags: ::__u32,
+                pub timeout_flags: ::__u32,
+                pub accept_flags: ::__u32,
+                pub cancel_flags: ::__u32,
+                pub open_flags: ::__u32,
+                pub statx_flags: ::__u32,
+                pub fadvise_advice: ::__u32,
+                pub splice_flags: ::__u32,
+                pub rename_flags: ::__u32,
+                pub unlink_flags: ::__u32,
+                pub hardlink_flags: ::__u32,
+                pub xattr_flags: ::__u32,
+                pub msg_ring_flags: ::__u32,
+                pub uring_cmd_flags: ::__u32,
+            }
+
+            #[allow(missing_debug_implementations)]
+            pub union __c_anonymous_io_uring_sqe_buf_index {
+                pub buf_index: ::__u16,
+                pub buf_group: ::__u16,
+            }
+
+            #[allow(missing_debug_implementations)]
+            pub union __c_anonymous_io_uring_sqe_splice_fd_in {
+                pub splice_fd_in: ::__s32,
+                pub file_index: ::__u32,
+                pub addr_len: __c_anonymous_io_uring_sqe_addr_len,
+            }
+
+            #[allow(missing_debug_implementations)]
+            pub union __c_anonymous_io_uring_sqe_cmd {
+                pub addr3: __c_anonymous_io_uring_sqe_addr3,
+                pub cmd: [::__u8; 0],
+            }
+
+            #[allow(missing_debug_implementations)]
+            pub struct io_uring_sqe {
+                pub opcode: ::__u8,
+                pub flags: ::__u8,
+                pub ioprio: ::__u16,
+                pub fd: ::__s32,
+                pub off__c_anonymous_union: __c_anonymous_io_uring_sqe_off,
+                pub addr__c_anonymous_union: __c_anonymous_io_uring_sqe_addr,
+                pub len: ::__u32,
+                pub rw_flags__c_anonymous_union: __c_anonymous_io_uring_sqe_rw_flags,
+                pub user_data: ::__u64,
+                pub buf_index__c_anonymous_union: __c_anonymous_io_uring_sqe_buf_index,
+                pub personality: ::__u16,
+                pub splice_fd_in__c_anonymous_union: __c_anonymous_io_uring_sqe_splice_fd_in,
+                pub addr3__c_anonymous_union: __c_anonymous_io_uring_sqe_cmd,
+            }
+        }
+    }
+}
+
+cfg_if! {
+    if #[cfg(libc_union)] {
+        s_no_extra_traits! {
+            // linux/userfaultfd.h
+            #[allow(missing_debug_implementations)]
+            pub union __c_anonymous_uffd_msg_pagefault_feat {
+                pub ptid: ::__u32,
+            }
+
+            #[allow(missing_debug_implementations)]
+            pub struct __c_anonymous_uffd_msg_pagefault {
+                pub flags: ::__u64,
+                pub address: ::__u64,
+                pub feat: __c_anonymous_uffd_msg_pagefault_feat,
+            }
+
+            #[allow(missing_debug_implementations)]
+            pub union __c_anonymous_uffd_msg_arg {
+                pub pagefault: __c_anonymous_uffd_msg_pagefault,
+                pub fork: __c_anonymous_uffd_msg_fork,
+                pub remap: __c_anonymous_uffd_msg_remap,
+                pub remove: __c_anonymous_uffd_msg_remove,
+                pub reserved: __c_anonymous_uffd_msg_reserved,
+            }
+
+            #[allow(missing_debug_implementations)]
+            #[repr(packed)]
+            pub struct uffd_msg {
+                pub event: ::__u8,
+                pub reserved1: ::__u8,
+                pub reserved2: ::__u16,
+                pub reserved3: ::__u32,
+                pub arg: __c_anonymous_uffd_msg_arg,
+            }
+        }
+    }
+}
+
 cfg_if! {
     if #[cfg(feature = "extra_traits")] {
         impl PartialEq for sockaddr_nl {
@@ -1252,6 +1610,149 @@ cfg_if! {
                 self.rx_filter.hash(state);
             }
         }
+
+        impl PartialEq for landlock_path_beneath_attr {
+            fn eq(&self, other: &landlock_path_beneath_attr) -> bool {
+                let (a, b) = (self.allowed_access, other.allowed_access);
+                let (c, d) = (self.parent_fd, other.parent_fd);
+                a == b && c == d
+            }
+        }
+        impl Eq for landlock_path_beneath_attr {}
+        impl ::fmt::Debug for landlock_path_beneath_attr {
+            fn fmt(&self, f: &mut ::fmt::Formatter) -> ::fmt::Result {
+                let allowed_access = self.allowed_access;
+                let parent_fd = self.parent_fd;
+                f.debug_struct("landlock_path_beneath_attr")
+                    .field("allowed_access", &allowed_access)
+                    .field("parent_fd", &parent_fd)
+                    .finish()
+            }
+        }
+        impl ::hash::Hash for landlock_path_beneath_attr {
+            fn hash<H: ::hash::Hasher>(&self, state: &mut H) {
+                let allowed_access = self.allowed_access;
+                let parent_fd = self.parent_fd;
+                allowed_access.hash(state);
+                parent_fd.hash(state);
+            }
+        }
+
+        impl PartialEq for statmount {
+            fn eq(&self, other: &statmount) -> bool {
+                self.size == other.size
+                    && self.mnt_opts == other.mnt_opts
+                    && self.mask == other.mask
+                    && self.sb_dev_major == other.sb_dev_major
+                    && self.sb_dev_minor == other.sb_dev_minor
+                    && self.sb_magic == other.sb_magic
+                    && self.sb_flags == other.sb_flags
+                    && self.fs_type == other.fs_type
+                    && self.mnt_id == other.mnt_id
+                    && self.mnt_parent_id == other.mnt_parent_id
+                    && self.mnt_id_old == other.mnt_id_old
+                    && self.mnt_parent_id_old == other.mnt_parent_id_old
+                    && self.mnt_attr == other.mnt_attr
+                    && self.mnt_propagation == other.mnt_propagation
+                    && self.mnt_peer_group == other.mnt_peer_group
+                    && self.mnt_master == other.mnt_master
+                    && self.propagate_from == other.propagate_from
+                    && self.mnt_root == other.mnt_root
+                    && self.mnt_point == other.mnt_point
+                    && self.mnt_ns_id == other.mnt_ns_id
+                    && self.fs_subtype == other.fs_subtype
+                    && self.sb_source == other.sb_source
+                    && self.opt_num == other.opt_num
+                    && self.opt_array == other.opt_array
+                    && self.opt_sec_num == other.opt_sec_num
+                    && self.opt_sec_array == other.opt_sec_array
+                    && self.supported_mask == other.supported_mask
+                    && self.mnt_uidmap_num == other.mnt_uidmap_num
+                    && self.mnt_uidmap == other.mnt_uidmap
+                    && self.mnt_gidmap_num == other.mnt_gidmap_num
+                    && self.mnt_gidmap == other.mnt_gidmap
+                    && self
+                        .__spare2
+                        .iter()
+                        .zip(other.__spare2.iter())
+                        .all(|(a, b)| a == b)
+            }
+        }
+        impl Eq for statmount {}
+        impl ::fmt::Debug for statmount {
+            fn fmt(&self, f: &mut ::fmt::Formatter) -> ::fmt::Result {
+                f.debug_struct("statmount")
+                    .field("size", &self.size)
+                    .field("mnt_opts", &self.mnt_opts)
+                    .field("mask", &self.mask)
+                    .field("sb_dev_major", &self.sb_dev_major)
+                    .field("sb_dev_minor", &self.sb_dev_minor)
+                    .field("sb_magic", &self.sb_magic)
+                    .field("sb_flags", &self.sb_flags)
+                    .field("fs_type", &self.fs_type)
+                    .field("mnt_id", &self.mnt_id)
+                    .field("mnt_parent_id", &self.mnt_parent_id)
+                    .field("mnt_id_old", &self.mnt_id_old)
+                    .field("mnt_parent_id_old", &self.mnt_parent_id_old)
+                    .field("mnt_attr", &self.mnt_attr)
+                    .field("mnt_propagation", &self.mnt_propagation)
+                    .field("mnt_peer_group", &self.mnt_peer_group)
+                    .field("mnt_master", &self.mnt_master)
+                    .field("propagate_from", &self.propagate_from)
+                    .field("mnt_root", &self.mnt_root)
+                    .field("mnt_point", &self.mnt_point)
+                    .field("mnt_ns_id", &self.mnt_ns_id)
+                    .field("fs_subtype", &self.fs_subtype)
+                    .field("sb_source", &self.sb_source)
+                    .field("opt_num", &self.opt_num)
+                    .field("opt_array", &self.opt_array)
+                    .field("opt_sec_num", &self.opt_sec_num)
+                    .field("opt_sec_array", &self.opt_sec_array)
+                    .field("supported_mask", &self.supported_mask)
+                    .field("mnt_uidmap_num", &self.mnt_uidmap_num)
+                    .field("mnt_uidmap", &self.mnt_uidmap)
+                    .field("mnt_gidmap_num", &self.mnt_gidmap_num)
+                    .field("mnt_gidmap", &self.mnt_gidmap)
+                    // FIXME: .field("__spare2", &self.__spare2)
+                    .finish()
+            }
+        }
+        impl ::hash::Hash for statmount {
+            fn hash<H: ::hash::Hasher>(&self, state: &mut H) {
+                self.size.hash(state);
+                self.mnt_opts.hash(state);
+                self.mask.hash(state);
+                self.sb_dev_major.hash(state);
+                self.sb_dev_minor.hash(state);
+                self.sb_magic.hash(state);
+                self.sb_flags.hash(state);
+                self.fs_type.hash(state);
+                self.mnt_id.hash(state);
+                self.mnt_parent_id.hash(state);
+                self.mnt_id_old.hash(state);
+                self.mnt_parent_id_old.hash(state);
+                self.mnt_attr.hash(state);
+                self.mnt_propagation.hash(state);
+                self.mnt_peer_group.hash(state);
+                self.mnt_master.hash(state);
+                self.propagate_from.hash(state);
+                self.mnt_root.hash(state);
+                self.mnt_point.hash(state);
+                self.mnt_ns_id.hash(state);
+                self.fs_subtype.hash(state);
+                self.sb_source.hash(state);
+                self.opt_num.hash(state);
+                self.opt_array.hash(state);
+                self.opt_sec_num.hash(state);
+                self.opt_sec_array.hash(state);
+                self.supported_mask.hash(state);
+                self.mnt_uidmap_num.hash(state);
+                self.mnt_uidmap.hash(state);
+                self.mnt_gidmap_num.hash(state);
+                self.mnt_gidmap.hash(state);
+                self.__spare2.hash(state);
+            }
+        }
     }
 }
 
@@ -2355,6 +2856,315 @@ pub const RESOLVE_BENEATH: ::__u64 = 0x08;
 pub const RESOLVE_IN_ROOT: ::__u64 = 0x10;
 pub const RESOLVE_CACHED: ::__u64 = 0x20;
 
+// linux/io_uring.h
+pub const IORING_FILE_INDEX_ALLOC: ::__u32 = !0;
+
+pub const IOSQE_FIXED_FILE: ::__u8 = 1 << 0;
+pub const IOSQE_IO_DRAIN: ::__u8 = 1 << 1;
+pub const IOSQE_IO_LINK: ::__u8 = 1 << 2;
+pub const IOSQE_IO_HARDLINK: ::__u8 = 1 << 3;
+pub const IOSQE_ASYNC: ::__u8 = 1 << 4;
+pub const IOSQE_BUFFER_SELECT: ::__u8 = 1 << 5;
+pub const IOSQE_CQE_SKIP_SUCCESS: ::__u8 = 1 << 6;
+
+pub const IORING_SETUP_IOPOLL: ::__u32 = 1 << 0;
+pub const IORING_SETUP_SQPOLL: ::__u32 = 1 << 1;
+pub const IORING_SETUP_SQ_AFF: ::__u32 = 1 << 2;
+pub const IORING_SETUP_CQSIZE: ::__u32 = 1 << 3;
+pub const IORING_SETUP_CLAMP: ::__u32 = 1 << 4;
+pub const IORING_SETUP_ATTACH_WQ: ::__u32 = 1 << 5;
+pub const IORING_SETUP_R_DISABLED: ::__u32 = 1 << 6;
+pub const IORING_SETUP_SUBMIT_ALL: ::__u32 = 1 << 7;
+pub const IORING_SETUP_COOP_TASKRUN: ::__u32 = 1 << 8;
+pub const IORING_SETUP_TASKRUN_FLAG: ::__u32 = 1 << 9;
+pub const IORING_SETUP_SQE128: ::__u32 = 1 << 10;
+pub const IORING_SETUP_CQE32: ::__u32 = 1 << 11;
+pub const IORING_SETUP_SINGLE_ISSUER: ::__u32 = 1 << 12;
+pub const IORING_SETUP_DEFER_TASKRUN: ::__u32 = 1 << 13;
+
+pub const IORING_OP_NOP: ::__u8 = 0;
+pub const IORING_OP_READV: ::__u8 = 1;
+pub const IORING_OP_WRITEV: ::__u8 = 2;
+pub const IORING_OP_FSYNC: ::__u8 = 3;
+pub const IORING_OP_READ_FIXED: ::__u8 = 4;
+pub const IORING_OP_WRITE_FIXED: ::__u8 = 5;
+pub const IORING_OP_POLL_ADD: ::__u8 = 6;
+pub const IORING_OP_POLL_REMOVE: ::__u8 = 7;
+pub const IORING_OP_SYNC_FILE_RANGE: ::__u8 = 8;
+pub const IORING_OP_SENDMSG: ::__u8 = 9;
+pub const IORING_OP_RECVMSG: ::__u8 = 10;
+pub const IORING_OP_TIMEOUT: ::__u8 = 11;
+pub const IORING_OP_TIMEOUT_REMOVE: ::__u8 = 12;
+pub const IORING_OP_ACCEPT: ::__u8 = 13;
+pub const IORING_OP_ASYNC_CANCEL: ::__u8 = 14;
+pub const IORING_OP_LINK_TIMEOUT: ::__u8 = 15;
+pub const IORING_OP_CONNECT: ::__u8 = 16;
+pub const IORING_OP_FALLOCATE: ::__u8 = 17;
+pub const IORING_OP_OPENAT: ::__u8 = 18;
+pub const IORING_OP_CLOSE: ::__u8 = 19;
+pub const IORING_OP_FILES_UPDATE: ::__u8 = 20;
+pub const IORING_OP_STATX: ::__u8 = 21;
+pub const IORING_OP_READ: ::__u8 = 22;
+pub const IORING_OP_WRITE: ::__u8 = 23;
+pub const IORING_OP_FADVISE: ::__u8 = 24;
+pub const IORING_OP_MADVISE: ::__u8 = 25;
+pub const IORING_OP_SEND: ::__u8 = 26;
+pub const IORING_OP_RECV: ::__u8 = 27;
+pub const IORING_OP_OPENAT2: ::__u8 = 28;
+pub const IORING_OP_EPOLL_CTL: ::__u8 = 29;
+pub const IORING_OP_SPLICE: ::__u8 = 30;
+pub const IORING_OP_PROVIDE_BUFFERS: ::__u8 = 31;
+pub const IORING_OP_REMOVE_BUFFERS: ::__u8 = 32;
+pub const IORING_OP_TEE: ::__u8 = 33;
+pub const IORING_OP_SHUTDOWN: ::__u8 = 34;
+pub const IORING_OP_RENAMEAT: ::__u8 = 35;
+pub const IORING_OP_UNLINKAT: ::__u8 = 36;
+pub const IORING_OP_MKDIRAT: ::__u8 = 37;
+pub const IORING_OP_SYMLINKAT: ::__u8 = 38;
+pub const IORING_OP_LINKAT: ::__u8 = 39;
+pub const IORING_OP_MSG_RING: ::__u8 = 40;
+pub const IORING_OP_FSETXATTR: ::__u8 = 41;
+pub const IORING_OP_SETXATTR: ::__u8 = 42;
+pub const IORING_OP_FGETXATTR: ::__u8 = 43;
+pub const IORING_OP_GETXATTR: ::__u8 = 44;
+pub const IORING_OP_SOCKET: ::__u8 = 45;
+pub const IORING_OP_URING_CMD: ::__u8 = 46;
+pub const IORING_OP_SEND_ZC: ::__u8 = 47;
+pub const IORING_OP_SENDMSG_ZC: ::__u8 = 48;
+
+pub const IORING_FSYNC_DATASYNC: ::__u32 = 1 << 0;
+
+pub const IORING_TIMEOUT_ABS: ::__u32 = 1 << 0;
+pub const IORING_TIMEOUT_UPDATE: ::__u32 = 1 << 1;
+pub const IORING_TIMEOUT_BOOTTIME: ::__u32 = 1 << 2;
+pub const IORING_TIMEOUT_REALTIME: ::__u32 = 1 << 3;
+pub const IORING_LINK_TIMEOUT_UPDATE: ::__u32 = 1 << 4;
+pub const IORING_TIMEOUT_ETIME_SUCCESS: ::__u32 = 1 << 5;
+pub const IORING_TIMEOUT_CLOCK_MASK: ::__u32 = IORING_TIMEOUT_BOOTTIME | IORING_TIMEOUT_REALTIME;
+pub const IORING_TIMEOUT_UPDATE_MASK: ::__u32 = IORING_TIMEOUT_UPDATE | IORING_LINK_TIMEOUT_UPDATE;
+
+pub const IORING_CQE_F_BUFFER: ::__u32 = 1 << 0;
+pub const IORING_CQE_F_MORE: ::__u32 = 1 << 1;
+pub const IORING_CQE_F_SOCK_NONEMPTY: ::__u32 = 1 << 2;
+pub const IORING_CQE_F_NOTIF: ::__u32 = 1 << 3;
+pub const IORING_CQE_BUFFER_SHIFT: ::__u32 = 16;
+
+pub const IORING_OFF_SQ_RING: ::__u64 = 0;
+pub const IORING_OFF_CQ_RING: ::__u64 = 0x8000000;
+pub const IORING_OFF_SQES: ::__u64 = 0x10000000;
+pub const IORING_OFF_MMAP_MASK: ::__u64 = 0xf8000000;
+
+pub const IORING_SQ_NEED_WAKEUP: ::__u32 = 1 << 0;
+pub const IORING_SQ_CQ_OVERFLOW: ::__u32 = 1 << 1;
+pub const IORING_SQ_TASKRUN: ::__u32 = 1 << 2;
+pub const IORING_CQ_EVENTFD_DISABLED: ::__u32 = 1 << 0;
+
+pub const IORING_ENTER_GETEVENTS: ::__u32 = 1 << 0;
+pub const IORING_ENTER_SQ_WAKEUP: ::__u32 = 1 << 1;
+pub const IORING_ENTER_SQ_WAIT: ::__u32 = 1 << 2;
+pub const IORING_ENTER_EXT_ARG: ::__u32 = 1 << 3;
+pub const IORING_ENTER_REGISTERED_RING: ::__u32 = 1 << 4;
+
+pub const IORING_FEAT_SINGLE_MMAP: ::__u32 = 1 << 0;
+pub const IORING_FEAT_NODROP: ::__u32 = 1 << 1;
+pub const IORING_FEAT_SUBMIT_STABLE: ::__u32 = 1 << 2;
+pub const IORING_FEAT_RW_CUR_POS: ::__u32 = 1 << 3;
+pub const IORING_FEAT_CUR_PERSONALITY: ::__u32 = 1 << 4;
+pub const IORING_FEAT_FAST_POLL: ::__u32 = 1 << 5;
+pub const IORING_FEAT_POLL_32BITS: ::__u32 = 1 << 6;
+pub const IORING_FEAT_SQPOLL_NONFIXED: ::__u32 = 1 << 7;
+pub const IORING_FEAT_EXT_ARG: ::__u32 = 1 << 8;
+pub const IORING_FEAT_NATIVE_WORKERS: ::__u32 = 1 << 9;
+pub const IORING_FEAT_RSRC_TAGS: ::__u32 = 1 << 10;
+pub const IORING_FEAT_CQE_SKIP: ::__u32 = 1 << 11;
+pub const IORING_FEAT_LINKED_FILE: ::__u32 = 1 << 12;
+
+pub const IORING_REGISTER_BUFFERS: ::__u32 = 0;
+pub const IORING_UNREGISTER_BUFFERS: ::__u32 = 1;
+pub const IORING_REGISTER_FILES: ::__u32 = 2;
+pub const IORING_UNREGISTER_FILES: ::__u32 = 3;
+pub const IORING_REGISTER_EVENTFD: ::__u32 = 4;
+pub const IORING_UNREGISTER_EVENTFD: ::__u32 = 5;
+pub const IORING_REGISTER_FILES_UPDATE: ::__u32 = 6;
+pub const IORING_REGISTER_EVENTFD_ASYNC: ::__u32 = 7;
+pub const IORING_REGISTER_PROBE: ::__u32 = 8;
+pub const IORING_REGISTER_PERSONALITY: ::__u32 = 9;
+pub const IORING_UNREGISTER_PERSONALITY: ::__u32 = 10;
+pub const IORING_REGISTER_RESTRICTIONS: ::__u32 = 11;
+pub const IORING_REGISTER_ENABLE_RINGS: ::__u32 = 12;
+pub const IORING_REGISTER_FILES2: ::__u32 = 13;
+pub const IORING_REGISTER_FILES_UPDATE2: ::__u32 = 14;
+pub const IORING_REGISTER_BUFFERS2: ::__u32 = 15;
+pub const IORING_REGISTER_BUFFERS_UPDATE: ::__u32 = 16;
+pub const IORING_REGISTER_IOWQ_AFF: ::__u32 = 17;
+pub const IORING_UNREGISTER_IOWQ_AFF: ::__u32 = 18;
+pub const IORING_REGISTER_IOWQ_MAX_WORKERS: ::__u32 = 19;
+pub const IORING_REGISTER_RING_FDS: ::__u32 = 20;
+pub const IORING_UNREGISTER_RING_FDS: ::__u32 = 21;
+pub const IORING_REGISTER_PBUF_RING: ::__u32 = 22;
+pub const IORING_UNREGISTER_PBUF_RING: ::__u32 = 23;
+pub const IORING_REGISTER_SYNC_CANCEL: ::__u32 = 24;
+pub const IORING_REGISTER_FILE_ALLOC_RANGE: ::__u32 = 25;
+pub const IORING_REGISTER_FILES_SKIP: ::c_int = -2;
+
+pub const IO_URING_OP_SUPPORTED: ::__u16 = 1 << 0;
+
+// linux/landlock.h
+pub const LANDLOCK_CREATE_RULESET_VERSION: ::__u32 = 1 << 0;
+
+pub const LANDLOCK_RULE_PATH_BENEATH: ::c_uint = 1;
+pub const LANDLOCK_RULE_NET_PORT: ::c_uint = 2;
+
+pub const LANDLOCK_ACCESS_FS_EXECUTE: ::__u64 = 1 << 0;
+pub const LANDLOCK_ACCESS_FS_WRITE_FILE: ::__u64 = 1 << 1;
+pub const LANDLOCK_ACCESS_FS_READ_FILE: ::__u64 = 1 << 2;
+pub const LANDLOCK_ACCESS_FS_READ_DIR: ::__u64 = 1 << 3;
+pub const LANDLOCK_ACCESS_FS_REMOVE_DIR: ::__u64 = 1 << 4;
+pub const LANDLOCK_ACCESS_FS_REMOVE_FILE: ::__u64 = 1 << 5;
+pub const LANDLOCK_ACCESS_FS_MAKE_CHAR: ::__u64 = 1 << 6;
+pub const LANDLOCK_ACCESS_FS_MAKE_DIR: ::__u64 = 1 << 7;
+pub const LANDLOCK_ACCESS_FS_MAKE_REG: ::__u64 = 1 << 8;
+pub const LANDLOCK_ACCESS_FS_MAKE_SOCK: ::__u64 = 1 << 9;
+pub const LANDLOCK_ACCESS_FS_MAKE_FIFO: ::__u64 = 1 << 10;
+pub const LANDLOCK_ACCESS_FS_MAKE_BLOCK: ::__u64 = 1 << 11;
+pub const LANDLOCK_ACCESS_FS_MAKE_SYM: ::__u64 = 1 << 12;
+pub const LANDLOCK_ACCESS_FS_REFER: ::__u64 = 1 << 13;
+pub const LANDLOCK_ACCESS_FS_TRUNCATE: ::__u64 = 1 << 14;
+pub const LANDLOCK_ACCESS_FS_IOCTL_DEV: ::__u64 = 1 << 15;
+
+pub const LANDLOCK_ACCESS_NET_BIND_TCP: ::__u64 = 1 << 0;
+pub const LANDLOCK_ACCESS_NET_CONNECT_TCP: ::__u64 = 1 << 1;
+
+pub const LANDLOCK_SCOPE_ABSTRACT_UNIX_SOCKET: ::__u64 = 1 << 0;
+pub const LANDLOCK_SCOPE_SIGNAL: ::__u64 = 1 << 1;
+
+// linux/userfaultfd.h
+pub const USERFAULTFD_IOC: ::c_uint = 0xAA;
+pub const UFFDIO: ::c_uint = 0xAA;
+pub const UFFD_API: ::__u64 = 0xAA;
+
+pub const _UFFDIO_REGISTER: ::c_uint = 0x00;
+pub const _UFFDIO_UNREGISTER: ::c_uint = 0x01;
+pub const _UFFDIO_WAKE: ::c_uint = 0x02;
+pub const _UFFDIO_COPY: ::c_uint = 0x03;
+pub const _UFFDIO_ZEROPAGE: ::c_uint = 0x04;
+pub const _UFFDIO_WRITEPROTECT: ::c_uint = 0x06;
+pub const _UFFDIO_CONTINUE: ::c_uint = 0x07;
+pub const _UFFDIO_POISON: ::c_uint = 0x08;
+pub const _UFFDIO_API: ::c_uint = 0x3F;
+
+pub const UFFD_EVENT_PAGEFAULT: ::__u8 = 0x12;
+pub const UFFD_EVENT_FORK: ::__u8 = 0x13;
+pub const UFFD_EVENT_REMAP: ::__u8 = 0x14;
+pub const UFFD_EVENT_REMOVE: ::__u8 = 0x15;
+pub const UFFD_EVENT_UNMAP: ::__u8 = 0x16;
+
+pub const UFFD_PAGEFAULT_FLAG_WRITE: ::__u64 = 1 << 0;
+pub const UFFD_PAGEFAULT_FLAG_WP: ::__u64 = 1 << 1;
+pub const UFFD_PAGEFAULT_FLAG_MINOR: ::__u64 = 1 << 2;
+
+pub const UFFD_FEATURE_PAGEFAULT_FLAG_WP: ::__u64 = 1 << 0;
+pub const UFFD_FEATURE_EVENT_FORK: ::__u64 = 1 << 1;
+pub const UFFD_FEATURE_EVENT_REMAP: ::__u64 = 1 << 2;
+pub const UFFD_FEATURE_EVENT_REMOVE: ::__u64 = 1 << 3;
+pub const UFFD_FEATURE_MISSING_HUGETLBFS: ::__u64 = 1 << 4;
+pub const UFFD_FEATURE_MISSING_SHMEM: ::__u64 = 1 << 5;
+pub const UFFD_FEATURE_EVENT_UNMAP: ::__u64 = 1 << 6;
+pub const UFFD_FEATURE_SIGBUS: ::__u64 = 1 << 7;
+pub const UFFD_FEATURE_THREAD_ID: ::__u64 = 1 << 8;
+pub const UFFD_FEATURE_MINOR_HUGETLBFS: ::__u64 = 1 << 9;
+pub const UFFD_FEATURE_MINOR_SHMEM: ::__u64 = 1 << 10;
+pub const UFFD_FEATURE_EXACT_ADDRESS: ::__u64 = 1 << 11;
+pub const UFFD_FEATURE_WP_HUGETLBFS_SHMEM: ::__u64 = 1 << 12;
+pub const UFFD_FEATURE_WP_UNPOPULATED: ::__u64 = 1 << 13;
+pub const UFFD_FEATURE_POISON: ::__u64 = 1 << 14;
+pub const UFFD_FEATURE_WP_ASYNC: ::__u64 = 1 << 15;
+
+pub const UFFDIO_REGISTER_MODE_MISSING: ::__u64 = 1 << 0;
+pub const UFFDIO_REGISTER_MODE_WP: ::__u64 = 1 << 1;
+pub const UFFDIO_REGISTER_MODE_MINOR: ::__u64 = 1 << 2;
+pub const UFFDIO_COPY_MODE_DONTWAKE: ::__u64 = 1 << 0;
+pub const UFFDIO_COPY_MODE_WP: ::__u64 = 1 << 1;
+pub const UFFDIO_ZEROPAGE_MODE_DONTWAKE: ::__u64 = 1 << 0;
+pub const UFFDIO_WRITEPROTECT_MODE_WP: ::__u64 = 1 << 0;
+pub const UFFDIO_WRITEPROTECT_MODE_DONTWAKE: ::__u64 = 1 << 1;
+pub const UFFDIO_CONTINUE_MODE_DONTWAKE: ::__u64 = 1 << 0;
+pub const UFFDIO_POISON_MODE_DONTWAKE: ::__u64 = 1 << 0;
+
+pub const UFFD_USER_MODE_ONLY: ::c_int = 1;
+
+// Helper macro to encode ioctl request numbers in constant context, the
+// `_IOC` family of functions is only `const` with `libc_const_extern_fn`.
+macro_rules! ioc {
+    ($dir:expr, $ty:expr, $nr:expr, $size:expr) => {
+        (($dir << _IOC_DIRSHIFT)
+            | ($ty << _IOC_TYPESHIFT)
+            | ($nr << _IOC_NRSHIFT)
+            | (($size as ::c_uint) << _IOC_SIZESHIFT)) as ::Ioctl
+    };
+}
+
+pub const USERFAULTFD_IOC_NEW: ::Ioctl = ioc!(_IOC_NONE, USERFAULTFD_IOC, 0x00, 0);
+
+cfg_if! {
+    if #[cfg(libc_const_size_of)] {
+        pub const UFFDIO_REGISTER: ::Ioctl = ioc!(
+            _IOC_READ | _IOC_WRITE,
+            UFFDIO,
+            _UFFDIO_REGISTER,
+            ::mem::size_of::<uffdio_register>()
+        );
+        pub const UFFDIO_UNREGISTER: ::Ioctl = ioc!(
+            _IOC_READ,
+            UFFDIO,
+            _UFFDIO_UNREGISTER,
+            ::mem::size_of::<uffdio_range>()
+        );
+        pub const UFFDIO_WAKE: ::Ioctl = ioc!(
+            _IOC_READ,
+            UFFDIO,
+            _UFFDIO_WAKE,
+            ::mem::size_of::<uffdio_range>()
+        );
+        pub const UFFDIO_COPY: ::Ioctl = ioc!(
+            _IOC_READ | _IOC_WRITE,
+            UFFDIO,
+            _UFFDIO_COPY,
+            ::mem::size_of::<uffdio_copy>()
+        );
+        pub const UFFDIO_ZEROPAGE: ::Ioctl = ioc!(
+            _IOC_READ | _IOC_WRITE,
+            UFFDIO,
+            _UFFDIO_ZEROPAGE,
+            ::mem::size_of::<uffdio_zeropage>()
+        );
+        pub const UFFDIO_WRITEPROTECT: ::Ioctl = ioc!(
+            _IOC_READ | _IOC_WRITE,
+            UFFDIO,
+            _UFFDIO_WRITEPROTECT,
+            ::mem::size_of::<uffdio_writeprotect>()
+        );
+        pub const UFFDIO_CONTINUE: ::Ioctl = ioc!(
+            _IOC_READ | _IOC_WRITE,
+            UFFDIO,
+            _UFFDIO_CONTINUE,
+            ::mem::size_of::<uffdio_continue>()
+        );
+        pub const UFFDIO_POISON: ::Ioctl = ioc!(
+            _IOC_READ | _IOC_WRITE,
+            UFFDIO,
+            _UFFDIO_POISON,
+            ::mem::size_of::<uffdio_poison>()
+        );
+        pub const UFFDIO_API: ::Ioctl = ioc!(
+            _IOC_READ | _IOC_WRITE,
+            UFFDIO,
+            _UFFDIO_API,
+            ::mem::size_of::<uffdio_api>()
+        );
+    }
+}
+
 // linux/if_ether.h
 pub const ETH_ALEN: ::c_int = 6;
 pub const ETH_HLEN: ::c_int = 14;
@@ -3327,6 +4137,70 @@ pub const IN_NONBLOCK: ::c_int = O_NONBLOCK;
 pub const OPEN_TREE_CLONE: ::c_uint = 0x01;
 pub const OPEN_TREE_CLOEXEC: ::c_uint = O_CLOEXEC as ::c_uint;
 
+pub const MOVE_MOUNT_F_SYMLINKS: ::c_uint = 0x00000001;
+pub const MOVE_MOUNT_F_AUTOMOUNTS: ::c_uint = 0x00000002;
+pub const MOVE_MOUNT_F_EMPTY_PATH: ::c_uint = 0x00000004;
+pub const MOVE_MOUNT_T_SYMLINKS: ::c_uint = 0x00000010;
+pub const MOVE_MOUNT_T_AUTOMOUNTS: ::c_uint = 0x00000020;
+pub const MOVE_MOUNT_T_EMPTY_PATH: ::c_uint = 0x00000040;
+pub const MOVE_MOUNT_SET_GROUP: ::c_uint = 0x00000100;
+pub const MOVE_MOUNT_BENEATH: ::c_uint = 0x00000200;
+
+pub const FSOPEN_CLOEXEC: ::c_uint = 0x00000001;
+
+pub const FSPICK_CLOEXEC: ::c_uint = 0x00000001;
+pub const FSPICK_SYMLINK_NOFOLLOW: ::c_uint = 0x00000002;
+pub const FSPICK_NO_AUTOMOUNT: ::c_uint = 0x00000004;
+pub const FSPICK_EMPTY_PATH: ::c_uint = 0x00000008;
+
+pub const FSCONFIG_SET_FLAG: ::c_uint = 0;
+pub const FSCONFIG_SET_STRING: ::c_uint = 1;
+pub const FSCONFIG_SET_BINARY: ::c_uint = 2;
+pub const FSCONFIG_SET_PATH: ::c_uint = 3;
+pub const FSCONFIG_SET_PATH_EMPTY: ::c_uint = 4;
+pub const FSCONFIG_SET_FD: ::c_uint = 5;
+pub const FSCONFIG_CMD_CREATE: ::c_uint = 6;
+pub const FSCONFIG_CMD_RECONFIGURE: ::c_uint = 7;
+pub const FSCONFIG_CMD_CREATE_EXCL: ::c_uint = 8;
+
+pub const FSMOUNT_CLOEXEC: ::c_uint = 0x00000001;
+
+pub const MOUNT_ATTR_RDONLY: ::c_uint = 0x00000001;
+pub const MOUNT_ATTR_NOSUID: ::c_uint = 0x00000002;
+pub const MOUNT_ATTR_NODEV: ::c_uint = 0x00000004;
+pub const MOUNT_ATTR_NOEXEC: ::c_uint = 0x00000008;
+pub const MOUNT_ATTR__ATIME: ::c_uint = 0x00000070;
+pub const MOUNT_ATTR_RELATIME: ::c_uint = 0x00000000;
+pub const MOUNT_ATTR_NOATIME: ::c_uint = 0x00000010;
+pub const MOUNT_ATTR_STRICTATIME: ::c_uint = 0x00000020;
+pub const MOUNT_ATTR_NODIRATIME: ::c_uint = 0x00000080;
+pub const MOUNT_ATTR_IDMAP: ::c_uint = 0x00100000;
+pub const MOUNT_ATTR_NOSYMFOLLOW: ::c_uint = 0x00200000;
+
+pub const MOUNT_ATTR_SIZE_VER0: ::c_int = 32;
+
+pub const MNT_ID_REQ_SIZE_VER0: ::c_int = 24;
+pub const MNT_ID_REQ_SIZE_VER1: ::c_int = 32;
+
+pub const STATMOUNT_SB_BASIC: ::__u64 = 0x00000001;
+pub const STATMOUNT_MNT_BASIC: ::__u64 = 0x00000002;
+pub const STATMOUNT_PROPAGATE_FROM: ::__u64 = 0x00000004;
+pub const STATMOUNT_MNT_ROOT: ::__u64 = 0x00000008;
+pub const STATMOUNT_MNT_POINT: ::__u64 = 0x00000010;
+pub const STATMOUNT_FS_TYPE: ::__u64 = 0x00000020;
+pub const STATMOUNT_MNT_NS_ID: ::__u64 = 0x00000040;
+pub const STATMOUNT_MNT_OPTS: ::__u64 = 0x00000080;
+pub const STATMOUNT_FS_SUBTYPE: ::__u64 = 0x00000100;
+pub const STATMOUNT_SB_SOURCE: ::__u64 = 0x00000200;
+pub const STATMOUNT_OPT_ARRAY: ::__u64 = 0x00000400;
+pub const STATMOUNT_OPT_SEC_ARRAY: ::__u64 = 0x00000800;
+pub const STATMOUNT_SUPPORTED_MASK: ::__u64 = 0x00001000;
+pub const STATMOUNT_MNT_UIDMAP: ::__u64 = 0x00002000;
+pub const STATMOUNT_MNT_GIDMAP: ::__u64 = 0x00004000;
+
+pub const LSMT_ROOT: ::__u64 = 0xffffffffffffffff;
+pub const LISTMOUNT_REVERSE: ::__u64 = 1 << 0;
+
 // uapi/linux/netfilter/nf_tables.h
 pub const NFT_TABLE_MAXNAMELEN: ::c_int = 256;
 pub const NFT_CHAIN_MAXNAMELEN: ::c_int = 256;
@@ -3927,6 +4801,18 @@ pub const DCCP_SOCKOPT_CCID_TX_INFO: ::c_int = 192;
 /// maximum number of services provided on the same listening port
 pub const DCCP_SERVICE_LIST_MAX_LEN: ::c_int = 32;
 
+// include/uapi/asm-generic/ioctl.h
+// The direction and size field widths are architecture specific and are
+// defined in `linux::arch`.
+pub const _IOC_NRMASK: ::c_uint = (1 << _IOC_NRBITS) - 1;
+pub const _IOC_TYPEMASK: ::c_uint = (1 << _IOC_TYPEBITS) - 1;
+pub const _IOC_SIZEMASK: ::c_uint = (1 << _IOC_SIZEBITS) - 1;
+pub const _IOC_DIRMASK: ::c_uint = (1 << _IOC_DIRBITS) - 1;
+pub const _IOC_NRSHIFT: ::c_uint = 0;
+pub const _IOC_TYPESHIFT: ::c_uint = _IOC_NRSHIFT + _IOC_NRBITS;
+pub const _IOC_SIZESHIFT: ::c_uint = _IOC_TYPESHIFT + _IOC_TYPEBITS;
+pub const _IOC_DIRSHIFT: ::c_uint = _IOC_SIZESHIFT + _IOC_SIZEBITS;
+
 f! {
     pub fn NLA_ALIGN(len: ::c_int) -> ::c_int {
         return ((len) + NLA_ALIGNTO - 1) & !(NLA_ALIGNTO - 1)
@@ -4094,6 +4980,45 @@ safe_f! {
     pub {const} fn SCTP_PR_PRIO_ENABLED(policy: ::c_int) -> bool {
         policy == SCTP_PR_SCTP_PRIO
     }
+
+    pub {const} fn _IOC(dir: ::c_uint, ty: ::c_uint, nr: ::c_uint, size: ::size_t) -> ::Ioctl {
+        ((dir << _IOC_DIRSHIFT)
+            | (ty << _IOC_TYPESHIFT)
+            | (nr << _IOC_NRSHIFT)
+            | ((size as ::c_uint) << _IOC_SIZESHIFT)) as ::Ioctl
+    }
+
+    pub {const} fn _IO(ty: ::c_uint, nr: ::c_uint) -> ::Ioctl {
+        _IOC(_IOC_NONE, ty, nr, 0)
+    }
+
+    pub {const} fn _IOR(ty: ::c_uint, nr: ::c_uint, size: ::size_t) -> ::Ioctl {
+        _IOC(_IOC_READ, ty, nr, size)
+    }
+
+    pub {const} fn _IOW(ty: ::c_uint, nr: ::c_uint, size: ::size_t) -> ::Ioctl {
+        _IOC(_IOC_WRITE, ty, nr, size)
+    }
+
+    pub {const} fn _IOWR(ty: ::c_uint, nr: ::c_uint, size: ::size_t) -> ::Ioctl {
+        _IOC(_IOC_READ | _IOC_WRITE, ty, nr, size)
+    }
+
+    pub {const} fn _IOC_DIR(nr: ::Ioctl) -> ::c_uint {
+        ((nr as ::c_uint) >> _IOC_DIRSHIFT) & _IOC_DIRMASK
+    }
+
+    pub {const} fn _IOC_TYPE(nr: ::Ioctl) -> ::c_uint {
+        ((nr as ::c_uint) >> _IOC_TYPESHIFT) & _IOC_TYPEMASK
+    }
+
+    pub {const} fn _IOC_NR(nr: ::Ioctl) -> ::c_uint {
+        ((nr as ::c_uint) >> _IOC_NRSHIFT) & _IOC_NRMASK
+    }
+
+    pub {const} fn _IOC_SIZE(nr: ::Ioctl) -> ::c_uint {
+        ((nr as ::c_uint) >> _IOC_SIZESHIFT) & _IOC_SIZEMASK
+    }
 }
 
 cfg_if! {